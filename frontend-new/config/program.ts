@@ -42,7 +42,9 @@ export const OUTCOME_LABELS: Record<number, string> = {
 }
 
 // ── PDA seeds ────────────────────────────────────────────────────────────────
-export const BETTING_POOL_SEED = "betting_pool"
-export const ROUND_SEED        = "round"
-export const BET_SEED          = "bet"
-export const SEASON_PRED_SEED  = "season_prediction"
+export const BETTING_POOL_SEED  = "betting_pool"
+export const ROUND_SEED         = "round"
+export const BET_SEED           = "bet"
+export const SEASON_PRED_SEED   = "season_prediction"
+export const SEASON_RESULT_SEED = "season_result"
+export const SEASON_VAULT_SEED  = "season_vault"
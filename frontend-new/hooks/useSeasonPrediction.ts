@@ -2,10 +2,14 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query"
 import { useWallet } from "@solana/wallet-adapter-react"
 import { Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js"
 import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token"
-import BN from "bn.js"
 import { useProgram } from "@/hooks/useProgram"
 import { useBettingPool } from "@/hooks/useBettingPool"
-import { getBettingPoolPda, getSeasonPredictionPda } from "@/utils/pda"
+import {
+  getBettingPoolPda,
+  getSeasonPredictionPda,
+  getSeasonResultPda,
+  getSeasonVaultPda,
+} from "@/utils/pda"
 import type { BettingPool, SeasonPrediction } from "@/types/sportsbook"
 
 // ── Fetch current user's season prediction ────────────────────────────────────
@@ -103,14 +107,11 @@ export function useMakeSeasonPrediction() {
 
 // ── Claim season reward ───────────────────────────────────────────────────────
 
-export interface ClaimSeasonRewardParams {
-  /** Total number of predictors for the season (fetched from getProgramAccounts count) */
-  totalPredictors: BN
-}
-
 /**
  * Claims the token reward for a correct season prediction.
  * Can only be called after the season has ended with a winning team declared.
+ * The reward is paid from the season vault to the current holder of the prediction NFT,
+ * weighted by the prediction's share of the winning team's total weight.
  */
 export function useClaimSeasonReward() {
   const { publicKey } = useWallet()
@@ -119,7 +120,7 @@ export function useClaimSeasonReward() {
   const [bettingPoolPda] = getBettingPoolPda()
 
   return useMutation({
-    mutationFn: async ({ totalPredictors }: ClaimSeasonRewardParams) => {
+    mutationFn: async () => {
       if (!publicKey) throw new Error("Wallet not connected")
 
       const poolData = await program.account.bettingPool.fetch(bettingPoolPda)
@@ -132,22 +133,23 @@ export function useClaimSeasonReward() {
         bettingPoolPda,
         pool.currentSeasonId
       )
+      const predictionData = await program.account.seasonPrediction.fetch(seasonPredictionPda)
+      const prediction = predictionData as unknown as SeasonPrediction
 
-      const tokenMint = pool.tokenMint
+      const [seasonResultPda] = getSeasonResultPda(bettingPoolPda, prediction.seasonId)
+      const [seasonVaultPda] = getSeasonVaultPda(bettingPoolPda)
 
-      const bettingPoolTokenAccount = await getAssociatedTokenAddress(
-        tokenMint,
-        bettingPoolPda,
-        true
-      )
-      const userTokenAccount = await getAssociatedTokenAddress(tokenMint, publicKey)
+      const nftTokenAccount = await getAssociatedTokenAddress(prediction.nftMint, publicKey)
+      const userTokenAccount = await getAssociatedTokenAddress(pool.tokenMint, publicKey)
 
       const tx = await program.methods
-        .claimSeasonReward(totalPredictors)
+        .claimSeasonReward()
         .accounts({
           bettingPool: bettingPoolPda,
           seasonPrediction: seasonPredictionPda,
-          bettingPoolTokenAccount,
+          seasonResult: seasonResultPda,
+          nftTokenAccount,
+          seasonVault: seasonVaultPda,
           userTokenAccount,
           user: publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
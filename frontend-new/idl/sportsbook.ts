@@ -122,12 +122,14 @@ export const IDL = {
       accounts: [
         { name: "bettingPool",             isMut: true,  isSigner: false },
         { name: "seasonPrediction",        isMut: true,  isSigner: false },
-        { name: "bettingPoolTokenAccount", isMut: true,  isSigner: false },
+        { name: "seasonResult",            isMut: true,  isSigner: false },
+        { name: "nftTokenAccount",         isMut: false, isSigner: false },
+        { name: "seasonVault",             isMut: true,  isSigner: false },
         { name: "userTokenAccount",        isMut: true,  isSigner: false },
         { name: "user",                    isMut: true,  isSigner: true  },
         { name: "tokenProgram",            isMut: false, isSigner: false },
       ],
-      args: [{ name: "totalPredictors", type: "u64" }],
+      args: [],
     },
     {
       name: "endSeason",
@@ -228,10 +230,26 @@ export const IDL = {
           { name: "nftMint",        type: "publicKey" },
           { name: "claimedReward",  type: "bool" },
           { name: "predictedAt",    type: "i64" },
+          { name: "nftRedeemed",    type: "bool" },
+          { name: "weight",         type: "u64" },
           { name: "bump",           type: "u8" },
         ],
       },
     },
+    {
+      name: "SeasonResult",
+      type: {
+        kind: "struct",
+        fields: [
+          { name: "seasonId",         type: "u64" },
+          { name: "winningTeam",      type: "u8" },
+          { name: "teamWeights",      type: { array: ["u64", 10] } },
+          { name: "rewardSnapshot",   type: "u64" },
+          { name: "unclaimedRewards", type: "u64" },
+          { name: "bump",             type: "u8" },
+        ],
+      },
+    },
   ],
   types: [
     {
@@ -13,7 +13,6 @@ import {
   useMySeasonPrediction,
 } from "@/hooks/useSeasonPrediction"
 import { TEAMS } from "@/config/program"
-import BN from "bn.js"
 
 export default function SeasonPage() {
   const { publicKey } = useWallet()
@@ -46,9 +45,8 @@ export default function SeasonPage() {
   function handleClaim() {
     setTxError(null)
     setSuccessMsg(null)
-    // totalPredictors is an approximation — in production you'd fetch the actual count
     claimReward(
-      { totalPredictors: new BN(1) },
+      undefined,
       {
         onSuccess: () => setSuccessMsg("Season reward claimed!"),
         onError: (e: Error) => setTxError(e.message ?? "Claim failed"),
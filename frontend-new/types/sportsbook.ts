@@ -95,9 +95,20 @@ export interface SeasonPrediction {
   nftMint:       PublicKey
   claimedReward: boolean
   predictedAt:   BN
+  nftRedeemed:   boolean
+  weight:        BN
   bump:          number
 }
 
+export interface SeasonResult {
+  seasonId:         BN
+  winningTeam:      number
+  teamWeights:      BN[]
+  rewardSnapshot:   BN
+  unclaimedRewards: BN
+  bump:             number
+}
+
 // ── UI / derived types ────────────────────────────────────────────────────────
 
 export type OutcomeId = 1 | 2 | 3
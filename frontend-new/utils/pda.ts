@@ -6,6 +6,8 @@ import {
   ROUND_SEED,
   BET_SEED,
   SEASON_PRED_SEED,
+  SEASON_RESULT_SEED,
+  SEASON_VAULT_SEED,
 } from "@/config/program"
 
 export function getBettingPoolPda(): [PublicKey, number] {
@@ -54,10 +56,32 @@ export function getSeasonPredictionPda(
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from(SEASON_PRED_SEED),
+      bettingPool.toBuffer(),
+      id.toArrayLike(Buffer, "le", 8),
       user.toBuffer(),
+    ],
+    PROGRAM_ID
+  )
+}
+
+export function getSeasonResultPda(
+  bettingPool: PublicKey,
+  seasonId: BN | number
+): [PublicKey, number] {
+  const id = typeof seasonId === "number" ? new BN(seasonId) : seasonId
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEASON_RESULT_SEED),
       bettingPool.toBuffer(),
       id.toArrayLike(Buffer, "le", 8),
     ],
     PROGRAM_ID
   )
 }
+
+export function getSeasonVaultPda(bettingPool: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEASON_VAULT_SEED), bettingPool.toBuffer()],
+    PROGRAM_ID
+  )
+}
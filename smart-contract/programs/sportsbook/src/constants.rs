@@ -15,6 +15,21 @@ pub const DEFAULT_WINNER_SHARE_BPS: u16 = 2500;
 /// Season pool share (2%)
 pub const DEFAULT_SEASON_POOL_SHARE_BPS: u16 = 200;
 
+/// House margin on cash-out quotes (5%)
+pub const DEFAULT_CASH_OUT_MARGIN_BPS: u16 = 500;
+
+/// Cash-out margin ceiling (20%)
+pub const MAX_CASH_OUT_MARGIN_BPS: u16 = 2000;
+
+/// Exposure repricing band around the seeded price (±20%)
+pub const DEFAULT_REPRICE_BAND_BPS: u16 = 2000;
+
+/// Repricing band ceiling (±50%)
+pub const MAX_REPRICE_BAND_BPS: u16 = 5000;
+
+/// Repriced odds never drop below 1.01x
+pub const MIN_REPRICED_ODDS: u64 = 1_010_000_000;
+
 /// MULTIBET STAKE BONUS RATES (basis points)
 /// Bonus added to pool upfront
 pub const BONUS_2_MATCH: u64 = 500;   // 5%
@@ -36,7 +51,12 @@ pub const PARLAY_MULTIPLIER_8_MATCHES: u64 = 1_230_000_000;       // 1.23x
 pub const PARLAY_MULTIPLIER_9_MATCHES: u64 = 1_240_000_000;       // 1.24x
 pub const PARLAY_MULTIPLIER_10_MATCHES: u64 = 1_250_000_000;      // 1.25x
 
-/// SEEDING AMOUNTS PER MATCH (assuming 9 decimals for LEAGUE token)
+/// TOKEN AMOUNTS
+/// Every token amount below is written for a 9-decimal mint and scaled to the
+/// betting mint's decimals at runtime (see `scale_token_amount`)
+pub const REFERENCE_TOKEN_DECIMALS: u8 = 9;
+
+/// SEEDING AMOUNTS PER MATCH (reference split at 9 decimals)
 /// These create initial odds in 1.2-1.8x range
 pub const SEED_HOME_POOL: u64 = 1_200_000_000_000;   // 1200 tokens (9 decimals)
 pub const SEED_AWAY_POOL: u64 = 800_000_000_000;     // 800 tokens
@@ -44,6 +64,17 @@ pub const SEED_DRAW_POOL: u64 = 1_000_000_000_000;   // 1000 tokens
 pub const SEED_PER_MATCH: u64 = 3_000_000_000_000;   // 3000 tokens
 pub const SEED_PER_ROUND: u64 = SEED_PER_MATCH * 10; // 30,000 tokens per round
 
+/// ROUND SEED SIZING
+/// Share of available LP liquidity put up as seed each round (10%)
+pub const DEFAULT_SEED_FRACTION_BPS: u16 = 1000;
+
+/// Seed fraction ceiling (50%)
+pub const MAX_SEED_FRACTION_BPS: u16 = 5000;
+
+/// Per-round seed floor (1,000 tokens) and ceiling (300,000 tokens)
+pub const MIN_SEED_PER_ROUND: u64 = 1_000_000_000_000;
+pub const MAX_SEED_PER_ROUND: u64 = SEED_PER_ROUND * 10;
+
 /// VIRTUAL LIQUIDITY MULTIPLIER
 /// Multiplier for virtual liquidity to dampen odds movement
 /// Set to 12M to create stable odds (can be adjusted)
@@ -90,8 +121,39 @@ pub const MAX_PAYOUT_PER_BET: u64 = 100_000_000_000_000;
 /// Max round payouts (500,000 tokens with 9 decimals)
 pub const MAX_ROUND_PAYOUTS: u64 = 500_000_000_000_000;
 
-/// Number of matches per round
-pub const MATCHES_PER_ROUND: usize = 10;
+/// Domain prefix of the odds oracle's signed round-odds payload
+pub const ROUND_ODDS_MESSAGE_PREFIX: &[u8] = b"sportsbook:round_odds";
+
+/// ROUND KINDS
+/// Simulated rounds settle from VRF randomness; fixture rounds cover real matches
+/// and settle from oracle-attested results
+pub const ROUND_SIMULATED: u8 = 0;
+pub const ROUND_FIXTURES: u8 = 1;
+
+/// Most keys in the results oracle set
+pub const MAX_ORACLE_SIGNERS: usize = 7;
+
+/// RESULTS CHALLENGE PERIOD
+/// Proposed results stay open to a bonded dispute this long before they can settle a round (2 hours)
+pub const DEFAULT_CHALLENGE_PERIOD_SECS: i64 = 2 * 3600;
+
+/// Longest challenge period (7 days)
+pub const MAX_CHALLENGE_PERIOD_SECS: i64 = 7 * 86400;
+
+/// Bond to dispute proposed results (1,000 tokens, forfeited if the dispute is rejected)
+pub const DEFAULT_DISPUTE_BOND: u64 = 1_000_000_000_000;
+
+/// Domain prefix of the results oracles' signed fixture-result payload
+pub const FIXTURE_RESULTS_MESSAGE_PREFIX: &[u8] = b"sportsbook:fixture_results";
+
+/// Most matches a round can hold (each round sets its own count; match bitmasks are u32)
+pub const MAX_MATCHES_PER_ROUND: usize = 32;
+
+/// Most legs a single bet can carry (leg bitmasks are u16)
+pub const MAX_LEGS_PER_BET: usize = 15;
+
+/// VRF randomness consumed per match (one 32-byte slice each)
+pub const VRF_BYTES_PER_MATCH: usize = 32;
 
 /// Odds compression constants (compress raw odds to 1.2x - 2.2x range)
 pub const MIN_COMPRESSED_ODDS: u64 = 1_200_000_000;  // 1.2x (strong favorites)
@@ -99,6 +161,25 @@ pub const MAX_COMPRESSED_ODDS: u64 = 2_200_000_000;  // 2.2x (heavy underdogs)
 pub const RAW_ODDS_MIN: u64 = 1_800_000_000;         // 1.8x raw (maps to 1.2x)
 pub const RAW_ODDS_MAX: u64 = 5_500_000_000;         // 5.5x raw (maps to 2.2x)
 
+/// ODDS PROFILE (tunable compression curve and per-market overround)
+/// Compression curves mapping raw odds into the compressed band
+pub const ODDS_CURVE_LINEAR: u8 = 0;
+/// S-curve: prices bunch towards both ends of the band
+pub const ODDS_CURVE_LOGISTIC: u8 = 1;
+/// t^p: p > 1 keeps prices near the floor longer, p < 1 lifts them sooner
+pub const ODDS_CURVE_POWER: u8 = 2;
+
+/// Curve parameter bounds (scaled by 1e9): logistic steepness / power exponent
+pub const MAX_LOGISTIC_STEEPNESS: u64 = 20_000_000_000; // 20
+pub const MIN_POWER_EXPONENT: u64 = 100_000_000;        // 0.1
+pub const MAX_POWER_EXPONENT: u64 = 5_000_000_000;      // 5
+
+/// Number of market types (MARKET_MATCH_RESULT..=MARKET_ASIAN_HANDICAP)
+pub const NUM_MARKET_TYPES: usize = 7;
+
+/// Highest target overround a profile may set (50%)
+pub const MAX_OVERROUND_BPS: u16 = 5000;
+
 /// TEAM TOKEN HOLDER BENEFITS
 /// Reduced protocol fee for team token holders (2% instead of 5%)
 pub const TEAM_TOKEN_FEE_BPS: u16 = 200;  // 2%
@@ -106,5 +187,163 @@ pub const TEAM_TOKEN_FEE_BPS: u16 = 200;  // 2%
 /// Odds boost for team token holders (5% better multiplier)
 pub const TEAM_TOKEN_ODDS_BOOST_BPS: u64 = 500;  // 5%
 
-/// Minimum team token balance required for benefits (1 token with 9 decimals)
+/// Minimum team token balance required for benefits (1 token with 9 decimals,
+/// scaled to the team token's own decimals)
 pub const MIN_TEAM_TOKEN_BALANCE: u64 = 1_000_000_000;
+
+/// SEASON OUTRIGHT MARKET
+/// Number of teams in the league
+pub const NUM_TEAMS: usize = 10;
+
+/// Virtual stake per team used to dampen outright odds (1,000 tokens with 9 decimals)
+/// With an empty book every team prices at NUM_TEAMS = 10x before the clamp
+pub const OUTRIGHT_VIRTUAL_STAKE_PER_TEAM: u64 = 1_000_000_000_000;
+
+/// Outright odds bounds (scaled by 1e9)
+pub const MIN_OUTRIGHT_ODDS: u64 = 1_500_000_000;   // 1.5x (heavily backed favourite)
+pub const MAX_OUTRIGHT_ODDS: u64 = 8_000_000_000;   // 8.0x (unbacked outsider)
+
+/// Max stake per outright bet (10,000 tokens with 9 decimals)
+pub const MAX_OUTRIGHT_STAKE: u64 = 10_000_000_000_000;
+
+/// SEASON PREDICTION WEIGHTING
+/// Weight of a prediction made before round 1 (100% in basis points)
+pub const PREDICTION_WEIGHT_SCALE: u64 = 10000;
+
+/// Weight floor for the latest allowed prediction (10%)
+pub const MIN_PREDICTION_WEIGHT: u64 = 1000;
+
+/// Default prediction cut-off: predictions close once 10 rounds have started
+pub const DEFAULT_PREDICTION_CUTOFF_ROUNDS: u64 = 10;
+
+/// Share of the season pool paid as consolation to burned losing NFTs (5%)
+pub const SEASON_CONSOLATION_SHARE_BPS: u64 = 500;
+
+/// SEASON PREDICTION NFT METADATA (Metaplex limits)
+pub const MAX_NFT_NAME_LEN: usize = 32;
+pub const MAX_NFT_SYMBOL_LEN: usize = 10;
+pub const MAX_NFT_URI_LEN: usize = 200;
+
+/// Room left in the URI for `/{season}/{team}/{timestamp}.json`
+pub const MAX_NFT_BASE_URI_LEN: usize = 140;
+
+/// ROUND PRICING MODES
+/// Fixed odds: bets pay at the odds they locked, the house books the risk
+pub const PRICING_FIXED_ODDS: u8 = 0;
+/// Parimutuel: winners split each market's pool pro rata, the house risks only the seed
+pub const PRICING_PARIMUTUEL: u8 = 1;
+
+/// MARKET TYPES (per prediction leg)
+/// 1X2 match result: outcomes 1=HOME_WIN, 2=AWAY_WIN, 3=DRAW
+pub const MARKET_MATCH_RESULT: u8 = 0;
+/// Over/under total goals: outcomes 1=OVER, 2=UNDER
+pub const MARKET_TOTAL_GOALS: u8 = 1;
+/// Correct score: outcomes 1-16 = grid score, 17 = any other score
+pub const MARKET_CORRECT_SCORE: u8 = 2;
+/// Double chance: outcomes 1=1X (home or draw), 2=X2 (away or draw), 3=12 (home or away)
+pub const MARKET_DOUBLE_CHANCE: u8 = 3;
+/// Draw no bet: outcomes 1=HOME, 2=AWAY, stake refunded on a draw
+pub const MARKET_DRAW_NO_BET: u8 = 4;
+/// Both teams to score: outcomes 1=YES, 2=NO
+pub const MARKET_BOTH_TEAMS_TO_SCORE: u8 = 5;
+/// Asian handicap: outcome = 1 + line_index × 2 + side (side 0=HOME, 1=AWAY)
+pub const MARKET_ASIAN_HANDICAP: u8 = 6;
+
+/// Over/under outcomes
+pub const OUTCOME_OVER: u8 = 1;
+pub const OUTCOME_UNDER: u8 = 2;
+
+/// Double chance outcomes
+pub const OUTCOME_HOME_OR_DRAW: u8 = 1;
+pub const OUTCOME_AWAY_OR_DRAW: u8 = 2;
+pub const OUTCOME_HOME_OR_AWAY: u8 = 3;
+
+/// Both teams to score outcomes
+pub const OUTCOME_BTTS_YES: u8 = 1;
+pub const OUTCOME_BTTS_NO: u8 = 2;
+
+/// SIMULATED SCORELINES (Poisson model, goal rates scaled by 1e9)
+/// Expected total goals in an evenly matched game (2.6 goals)
+pub const BASE_EXPECTED_GOALS: u64 = 2_600_000_000;
+
+/// Extra expected goals at maximum mismatch between the two sides (1.0 goal)
+pub const MISMATCH_EXPECTED_GOALS: u64 = 1_000_000_000;
+
+/// Floor on a single team's goal rate (0.2 goals)
+pub const MIN_TEAM_GOAL_RATE: u64 = 200_000_000;
+
+/// Cap on goals generated for one team in one match
+pub const MAX_GOALS_PER_TEAM: u8 = 9;
+
+/// OVER/UNDER MARKET
+/// Goal line 2.5: UNDER wins on 0-2 total goals, OVER on 3 or more
+pub const OVER_UNDER_LINE_GOALS: u8 = 2;
+
+/// Bookmaker margin taken off fair over/under odds (5%)
+pub const OVER_UNDER_MARGIN_BPS: u64 = 500;
+
+/// CORRECT SCORE MARKET
+/// Grid covers 0-3 goals per side: outcome = 1 + home_goals × 4 + away_goals
+pub const CORRECT_SCORE_MAX_GOALS: u8 = 3;
+
+/// Number of grid scores (4 × 4)
+pub const CORRECT_SCORE_GRID_SIZE: usize = 16;
+
+/// "Any other" bucket outcome (any score outside the grid)
+pub const CORRECT_SCORE_ANY_OTHER: u8 = 17;
+
+/// Total correct score outcomes (grid + any other)
+pub const NUM_CORRECT_SCORE_OUTCOMES: usize = 17;
+
+/// Bookmaker margin taken off fair correct score odds (15%)
+pub const CORRECT_SCORE_MARGIN_BPS: u64 = 1500;
+
+/// Correct score odds bounds (scaled by 1e9)
+pub const MIN_CORRECT_SCORE_ODDS: u64 = 3_000_000_000;   // 3x
+pub const MAX_CORRECT_SCORE_ODDS: u64 = 50_000_000_000;  // 50x
+
+/// Max owed on any single correct score selection of a match (50,000 tokens with 9 decimals)
+pub const MAX_CORRECT_SCORE_LIABILITY_PER_MATCH: u64 = 50_000_000_000_000;
+
+/// DOUBLE CHANCE / DRAW NO BET (priced from the match's locked 1X2 odds)
+/// Bookmaker margin taken off fair odds (5%)
+pub const DERIVED_MARKET_MARGIN_BPS: u64 = 500;
+
+/// Odds floor (1.02x)
+pub const MIN_DERIVED_MARKET_ODDS: u64 = 1_020_000_000;
+
+/// BOTH TEAMS TO SCORE MARKET
+/// Bookmaker margin taken off fair BTTS odds (5%)
+pub const BTTS_MARGIN_BPS: u64 = 500;
+
+/// ASIAN HANDICAP MARKET
+/// Home handicap lines in quarter goals: -1, -0.75, -0.5, -0.25, 0, +0.25, +0.5, +0.75, +1
+/// The away side takes the opposite line. Quarter lines settle half on each neighbouring line.
+pub const ASIAN_HANDICAP_LINES: [i8; 9] = [-4, -3, -2, -1, 0, 1, 2, 3, 4];
+
+/// Total Asian handicap outcomes (9 lines × 2 sides)
+pub const NUM_ASIAN_HANDICAP_OUTCOMES: usize = 18;
+
+/// Bookmaker margin taken off fair Asian handicap odds (5%)
+pub const ASIAN_HANDICAP_MARGIN_BPS: u64 = 500;
+
+/// Asian handicap odds bounds (scaled by 1e9)
+pub const MIN_ASIAN_HANDICAP_ODDS: u64 = 1_050_000_000;  // 1.05x
+pub const MAX_ASIAN_HANDICAP_ODDS: u64 = 5_000_000_000;  // 5x
+
+/// GENERIC MARKETS
+/// Most selections any market offers (Asian handicap: 9 lines × 2 sides)
+pub const MAX_MARKET_SELECTIONS: usize = 18;
+
+/// SYSTEM BETS (bit k-1 set = every k-fold combination of the legs is included)
+/// Most legs a system bet can cover
+pub const MAX_SYSTEM_SELECTIONS: usize = 8;
+
+/// Trixie: 3 doubles + 1 treble on 3 legs
+pub const SYSTEM_TRIXIE: u16 = 0b110;
+
+/// Yankee: 6 doubles + 4 trebles + 1 four-fold on 4 legs
+pub const SYSTEM_YANKEE: u16 = 0b1110;
+
+/// Lucky 15: 4 singles + 6 doubles + 4 trebles + 1 four-fold on 4 legs
+pub const SYSTEM_LUCKY_15: u16 = 0b1111;
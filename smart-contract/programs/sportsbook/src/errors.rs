@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum SportsbookError {
-    #[msg("Invalid match index (must be 0-9)")]
+    #[msg("Invalid match index (must be below the round's match count)")]
     InvalidMatchIndex,
 
     #[msg("Invalid outcome (must be 1, 2, or 3)")]
@@ -85,4 +85,148 @@ pub enum SportsbookError {
 
     #[msg("Numerical overflow")]
     NumericalOverflow,
+
+    #[msg("Invalid team index (must be 0-9)")]
+    InvalidTeamIndex,
+
+    #[msg("Season already ended")]
+    SeasonAlreadyEnded,
+
+    #[msg("Season not ended yet")]
+    SeasonNotEnded,
+
+    #[msg("Position did not back the season winner")]
+    NotSeasonWinner,
+
+    #[msg("Season prediction deadline has passed")]
+    PredictionDeadlinePassed,
+
+    #[msg("NFT metadata field too long")]
+    NftMetadataTooLong,
+
+    #[msg("Season NFT collection not initialized")]
+    SeasonCollectionNotInitialized,
+
+    #[msg("Invalid market type")]
+    InvalidMarketType,
+
+    #[msg("Match appears more than once in the bet")]
+    DuplicateMatchInBet,
+
+    #[msg("Liability cap reached for this market selection")]
+    MarketLiabilityExceeded,
+
+    #[msg("Market account does not match the bet leg")]
+    MarketMismatch,
+
+    #[msg("Market has not been resolved yet")]
+    MarketNotResolved,
+
+    #[msg("Market already resolved")]
+    MarketAlreadyResolved,
+
+    #[msg("Invalid system bet combination sizes")]
+    InvalidSystemBet,
+
+    #[msg("Cash-out margin exceeds maximum")]
+    CashOutMarginTooHigh,
+
+    #[msg("Cash-out fraction must be between 1 and 9999 bps")]
+    InvalidCashOutFraction,
+
+    #[msg("Invalid bet leg index")]
+    InvalidLegIndex,
+
+    #[msg("Reprice band exceeds maximum")]
+    RepriceBandTooHigh,
+
+    #[msg("Invalid pricing mode")]
+    InvalidPricingMode,
+
+    #[msg("Cash-out is not available in parimutuel rounds")]
+    CashOutUnavailable,
+
+    #[msg("Invalid odds profile")]
+    InvalidOddsProfile,
+
+    #[msg("Seed fraction exceeds maximum")]
+    SeedFractionTooHigh,
+
+    #[msg("Signer is not the odds oracle")]
+    NotOddsOracle,
+
+    #[msg("Missing or invalid oracle signature")]
+    InvalidOracleSignature,
+
+    #[msg("Submitted odds break the odds band or overround rules")]
+    InvalidRoundOdds,
+
+    #[msg("Instruction is not available for this round kind")]
+    InvalidRoundKind,
+
+    #[msg("Invalid fixtures")]
+    InvalidFixtures,
+
+    #[msg("Betting on this match has closed")]
+    BettingClosed,
+
+    #[msg("Invalid oracle set")]
+    InvalidOracleSet,
+
+    #[msg("Not enough oracle signatures on the attestation")]
+    AttestationThresholdNotMet,
+
+    #[msg("Results have already been proposed")]
+    ResultsAlreadyProposed,
+
+    #[msg("No proposed results pending")]
+    ResultsNotProposed,
+
+    #[msg("Challenge period is still open")]
+    ChallengePeriodOpen,
+
+    #[msg("Challenge period has closed")]
+    ChallengePeriodClosed,
+
+    #[msg("Proposed results are under dispute")]
+    ResultsDisputed,
+
+    #[msg("Signer is not the results resolver")]
+    NotResultsResolver,
+
+    #[msg("Invalid challenge period")]
+    InvalidChallengePeriod,
+
+    #[msg("Match result has already settled")]
+    MatchAlreadySettled,
+
+    #[msg("Match result has not settled yet")]
+    MatchNotSettled,
+
+    #[msg("Bet can still win")]
+    BetStillLive,
+
+    #[msg("Betting cutoff must be in the future")]
+    InvalidBettingCutoff,
+
+    #[msg("Randomness already requested for this match")]
+    RandomnessAlreadyRequested,
+
+    #[msg("Previous VRF request is still pending")]
+    VrfRequestPending,
+
+    #[msg("Invalid cross-round bet")]
+    InvalidCrossRoundBet,
+
+    #[msg("Open cross-round bets still depend on this round")]
+    CrossRoundBetsOpen,
+
+    #[msg("Invalid match count (must be between 1 and the maximum matches per round)")]
+    InvalidMatchCount,
+
+    #[msg("Odds sequence must exceed the round's last applied price set")]
+    StaleOddsSequence,
+
+    #[msg("Outright staking closed at the season's prediction cut-off")]
+    OutrightStakingClosed,
 }
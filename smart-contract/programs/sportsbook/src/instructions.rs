@@ -2,22 +2,44 @@ pub mod initialize;
 pub mod initialize_round;
 pub mod seed_round;
 pub mod place_bet;
-pub mod settle_round;
+pub mod results;
 pub mod claim_winnings;
+pub mod mark_bet_dead;
 pub mod finalize_revenue;
 pub mod vrf_request;
 pub mod vrf_fulfill;
 pub mod season_prediction;
 pub mod end_season;
+pub mod season_outright;
+pub mod season_collection;
+pub mod season_vault;
+pub mod create_market;
+pub mod resolve_market;
+pub mod cash_out;
+pub mod edit_bet;
+pub mod odds_profile;
+pub mod set_round_odds;
+pub mod fixtures;
 
 pub use initialize::*;
 pub use initialize_round::*;
 pub use seed_round::*;
 pub use place_bet::*;
-pub use settle_round::*;
+pub use results::*;
 pub use claim_winnings::*;
+pub use mark_bet_dead::*;
 pub use finalize_revenue::*;
 pub use vrf_request::*;
 pub use vrf_fulfill::*;
 pub use season_prediction::*;
 pub use end_season::*;
+pub use season_outright::*;
+pub use season_collection::*;
+pub use season_vault::*;
+pub use create_market::*;
+pub use resolve_market::*;
+pub use cash_out::*;
+pub use edit_bet::*;
+pub use odds_profile::*;
+pub use set_round_odds::*;
+pub use fixtures::*;
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{BettingPool, RoundAccounting, Bet, Market};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::{calculate_market_odds, calculate_cash_out_quote, scale_token_amount};
+
+#[derive(Accounts)]
+#[instruction(bet_id: u64)]
+pub struct CashOut<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), bet.round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = round_accounting.pricing_mode == PRICING_FIXED_ODDS @ SportsbookError::CashOutUnavailable,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [b"bet", betting_pool.key().as_ref(), bet_id.to_le_bytes().as_ref()],
+        bump = bet.bump,
+        has_one = bettor @ SportsbookError::NotBettor,
+        close = bettor,
+    )]
+    pub bet: Box<Account<'info, Bet>>,
+
+    /// Betting pool's token account (pays the cash-out)
+    #[account(mut)]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Bettor's token account (receives the cash-out)
+    #[account(mut)]
+    pub bettor_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub bettor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    // Remaining accounts: one writable Market per prediction, in prediction order
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CashOut<'info>>,
+    bet_id: u64,
+    min_amount: u64,
+) -> Result<()> {
+    let betting_pool_info = ctx.accounts.betting_pool.to_account_info();
+    let betting_pool_bump = ctx.accounts.betting_pool.bump;
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let margin_bps = ctx.accounts.betting_pool.cash_out_margin_bps as u64;
+    let token_decimals = ctx.accounts.betting_pool.token_decimals;
+
+    let predictions = ctx.accounts.bet.get_predictions().to_vec();
+    let mut markets = load_bet_markets(ctx.remaining_accounts, &ctx.accounts.bet, betting_pool_key)?;
+    require_pre_kickoff(&ctx.accounts.round_accounting, &ctx.accounts.bet)?;
+    let current_odds = current_leg_odds(&ctx.accounts.bet, &markets);
+
+    let quote = calculate_cash_out_quote(
+        &ctx.accounts.bet,
+        &current_odds,
+        margin_bps,
+        scale_token_amount(MAX_PAYOUT_PER_BET, token_decimals),
+    )
+    .map_err(|_| SportsbookError::CalculationOverflow)?;
+
+    // Slippage protection
+    require!(quote >= min_amount, SportsbookError::PayoutBelowMinimum);
+    require!(
+        ctx.accounts.round_accounting.total_paid_out + quote <= scale_token_amount(MAX_ROUND_PAYOUTS, token_decimals),
+        SportsbookError::RoundPayoutLimitReached
+    );
+    require!(
+        ctx.accounts.betting_pool_token_account.amount >= quote,
+        SportsbookError::InsufficientProtocolLiquidity
+    );
+
+    // Release the bet's liability from every market it was placed in
+    for (prediction, market) in predictions.iter().zip(markets.iter_mut()) {
+        market.remove_stake(prediction.predicted_outcome, prediction.amount_in_pool, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+    }
+
+    ctx.accounts.round_accounting.total_paid_out += quote;
+
+    if quote > 0 {
+        let seeds = &[b"betting_pool".as_ref(), &[betting_pool_bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.betting_pool_token_account.to_account_info(),
+            to: ctx.accounts.bettor_token_account.to_account_info(),
+            authority: betting_pool_info,
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, quote)?;
+    }
+
+    msg!("Bet {} cashed out for {} tokens (margin {}bps)", bet_id, quote, margin_bps);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bet_id: u64)]
+pub struct PartialCashOut<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), bet.round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = round_accounting.pricing_mode == PRICING_FIXED_ODDS @ SportsbookError::CashOutUnavailable,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [b"bet", betting_pool.key().as_ref(), bet_id.to_le_bytes().as_ref()],
+        bump = bet.bump,
+        has_one = bettor @ SportsbookError::NotBettor,
+    )]
+    pub bet: Box<Account<'info, Bet>>,
+
+    /// Betting pool's token account (pays the cash-out)
+    #[account(mut)]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Bettor's token account (receives the cash-out)
+    #[account(mut)]
+    pub bettor_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub bettor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    // Remaining accounts: one writable Market per prediction, in prediction order
+}
+
+/// Cash out `fraction_bps` of a bet, leaving the rest of the stake riding
+pub fn partial_cash_out_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PartialCashOut<'info>>,
+    bet_id: u64,
+    fraction_bps: u16,
+    min_amount: u64,
+) -> Result<()> {
+    let betting_pool_info = ctx.accounts.betting_pool.to_account_info();
+    let betting_pool_bump = ctx.accounts.betting_pool.bump;
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let margin_bps = ctx.accounts.betting_pool.cash_out_margin_bps as u64;
+    let token_decimals = ctx.accounts.betting_pool.token_decimals;
+
+    let mut markets = load_bet_markets(ctx.remaining_accounts, &ctx.accounts.bet, betting_pool_key)?;
+    require_pre_kickoff(&ctx.accounts.round_accounting, &ctx.accounts.bet)?;
+    let current_odds = current_leg_odds(&ctx.accounts.bet, &markets);
+
+    // The quote is linear in the stake, so a fraction of the bet is worth that fraction of the quote
+    let full_quote = calculate_cash_out_quote(
+        &ctx.accounts.bet,
+        &current_odds,
+        margin_bps,
+        scale_token_amount(MAX_PAYOUT_PER_BET, token_decimals),
+    )
+    .map_err(|_| SportsbookError::CalculationOverflow)?;
+    let quote = (full_quote as u128)
+        .checked_mul(fraction_bps as u128)
+        .ok_or(SportsbookError::CalculationOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(SportsbookError::CalculationOverflow)? as u64;
+
+    // Slippage protection
+    require!(quote >= min_amount, SportsbookError::PayoutBelowMinimum);
+    require!(
+        ctx.accounts.round_accounting.total_paid_out + quote <= scale_token_amount(MAX_ROUND_PAYOUTS, token_decimals),
+        SportsbookError::RoundPayoutLimitReached
+    );
+    require!(
+        ctx.accounts.betting_pool_token_account.amount >= quote,
+        SportsbookError::InsufficientProtocolLiquidity
+    );
+
+    // Shrink the bet and pull the cashed-out share of each leg from its market
+    let removed = ctx.accounts.bet.reduce_stake(fraction_bps as u64)?;
+    let predictions = ctx.accounts.bet.get_predictions().to_vec();
+    for ((prediction, market), amount) in predictions.iter().zip(markets.iter_mut()).zip(removed) {
+        market.remove_stake(prediction.predicted_outcome, amount, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+    }
+
+    ctx.accounts.round_accounting.total_paid_out += quote;
+
+    if quote > 0 {
+        let seeds = &[b"betting_pool".as_ref(), &[betting_pool_bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.betting_pool_token_account.to_account_info(),
+            to: ctx.accounts.bettor_token_account.to_account_info(),
+            authority: betting_pool_info,
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, quote)?;
+    }
+
+    msg!("Bet {}: cashed out {}bps for {} tokens", bet_id, fraction_bps, quote);
+    msg!("Remaining stake: {}", ctx.accounts.bet.amount_after_fee);
+
+    Ok(())
+}
+
+/// Load and validate the market of every leg of a bet (remaining accounts, prediction order)
+pub fn load_bet_markets<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    bet: &Bet,
+    betting_pool_key: Pubkey,
+) -> Result<Vec<Account<'info, Market>>> {
+    let predictions = bet.get_predictions();
+    require!(
+        remaining_accounts.len() == predictions.len(),
+        SportsbookError::MarketMismatch
+    );
+
+    let mut markets = Vec::with_capacity(predictions.len());
+    for (prediction, market_info) in predictions.iter().zip(remaining_accounts.iter()) {
+        require!(market_info.is_writable, SportsbookError::MarketMismatch);
+        let market: Account<Market> = Account::try_from(market_info)?;
+        require!(
+            market.betting_pool == betting_pool_key
+                && market.round_id == prediction.round_id
+                && market.match_index == prediction.match_index
+                && market.market_type == prediction.market_type,
+            SportsbookError::MarketMismatch
+        );
+        markets.push(market);
+    }
+
+    Ok(markets)
+}
+
+/// Cash-out is priced pre-match, so it closes once any of the bet's matches closes to betting
+/// (cross-round bets, priced across several rounds, can't be cashed out)
+fn require_pre_kickoff(round_accounting: &RoundAccounting, bet: &Bet) -> Result<()> {
+    require!(bet.other_rounds().is_empty(), SportsbookError::CashOutUnavailable);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        bet.get_predictions()
+            .iter()
+            .all(|p| round_accounting.match_open(p.match_index as usize, now)),
+        SportsbookError::BettingClosed
+    );
+    Ok(())
+}
+
+/// Current market odds for each leg of a bet
+fn current_leg_odds(bet: &Bet, markets: &[Account<Market>]) -> Vec<u64> {
+    bet.get_predictions()
+        .iter()
+        .zip(markets.iter())
+        .map(|(prediction, market)| calculate_market_odds(market, prediction.predicted_outcome))
+        .collect()
+}
+
+#[derive(Accounts)]
+pub struct SetCashOutMargin<'info> {
+    #[account(mut)]
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_cash_out_margin_handler(ctx: Context<SetCashOutMargin>, margin_bps: u16) -> Result<()> {
+    require!(
+        margin_bps <= MAX_CASH_OUT_MARGIN_BPS,
+        SportsbookError::CashOutMarginTooHigh
+    );
+
+    ctx.accounts.betting_pool.cash_out_margin_bps = margin_bps;
+
+    msg!("Cash-out margin set to {}bps", margin_bps);
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{BettingPool, RoundAccounting, Bet, MatchOutcome};
+use crate::state::{BettingPool, RoundAccounting, Bet, LegResult, Market};
 use crate::errors::SportsbookError;
 use crate::constants::*;
+use crate::utils::{bet_combinations, combination_legs, scale_token_amount};
+use super::place_bet::{load_leg_rounds, release_cross_round_bet};
 
 #[derive(Accounts)]
 #[instruction(bet_id: u64)]
@@ -14,9 +16,8 @@ pub struct ClaimWinnings<'info> {
         mut,
         seeds = [b"round", betting_pool.key().as_ref(), bet.round_id.to_le_bytes().as_ref()],
         bump = round_accounting.bump,
-        constraint = round_accounting.settled @ SportsbookError::RoundNotSettled,
     )]
-    pub round_accounting: Account<'info, RoundAccounting>,
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
 
     #[account(
         mut,
@@ -48,10 +49,13 @@ pub struct ClaimWinnings<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    // Remaining accounts: the resolved Market of each prediction, in prediction order,
+    // then the writable RoundAccounting of every other round the legs reference, in leg order
 }
 
-pub fn handler(
-    ctx: Context<ClaimWinnings>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimWinnings<'info>>,
     bet_id: u64,
     min_payout: u64,
 ) -> Result<()> {
@@ -62,9 +66,36 @@ pub fn handler(
     let betting_pool_info = ctx.accounts.betting_pool.to_account_info();
     let betting_pool_bump = ctx.accounts.betting_pool.bump;
 
-    // Calculate claim deadline: 24 hours after round settlement
+    // Cross-round legs are settled in their own rounds, whose accounting follows the markets
+    let num_predictions = ctx.accounts.bet.num_predictions as usize;
+    require!(
+        ctx.remaining_accounts.len() >= num_predictions,
+        SportsbookError::MarketMismatch
+    );
+    let (market_infos, round_infos) = ctx.remaining_accounts.split_at(num_predictions);
+    let mut other_rounds = load_leg_rounds(
+        round_infos,
+        &ctx.accounts.bet.other_rounds(),
+        ctx.accounts.betting_pool.key(),
+    )?;
+
+    // Calculate claim deadline: 24 hours after the bet's last match settled
+    // (matches settle individually, so a single can be claimed before the round ends)
     // 86400 seconds = 24 hours
-    let claim_deadline = ctx.accounts.round_accounting.round_end_time + 86400;
+    let mut last_settled_at = 0i64;
+    for prediction in ctx.accounts.bet.get_predictions() {
+        let leg_round: &RoundAccounting = if prediction.round_id == ctx.accounts.bet.round_id {
+            &ctx.accounts.round_accounting
+        } else {
+            other_rounds
+                .iter()
+                .find(|r| r.round_id == prediction.round_id)
+                .ok_or(SportsbookError::InvalidCrossRoundBet)?
+        };
+        last_settled_at = last_settled_at.max(leg_round.match_settled_at[prediction.match_index as usize]);
+    }
+    require!(last_settled_at != 0, SportsbookError::MatchNotSettled);
+    let claim_deadline = last_settled_at + 86400;
 
     // Update bet's claim_deadline if not set yet
     if ctx.accounts.bet.claim_deadline == 0 {
@@ -80,8 +111,33 @@ pub fn handler(
         require!(is_bettor, SportsbookError::NotBettor);
     }
 
+    // Settle each leg in its market
+    let predictions = ctx.accounts.bet.get_predictions();
+    let mut legs = Vec::with_capacity(predictions.len());
+    for (prediction, market_info) in predictions.iter().zip(market_infos.iter()) {
+        let market: Account<Market> = Account::try_from(market_info)?;
+        require!(
+            market.betting_pool == ctx.accounts.betting_pool.key()
+                && market.round_id == prediction.round_id
+                && market.match_index == prediction.match_index
+                && market.market_type == prediction.market_type,
+            SportsbookError::MarketMismatch
+        );
+        require!(market.resolved, SportsbookError::MarketNotResolved);
+
+        let selection = market
+            .selection(prediction.predicted_outcome)
+            .ok_or(SportsbookError::InvalidOutcome)?;
+        legs.push((selection.result, market.settlement_odds(prediction.predicted_outcome)));
+    }
+
     // Calculate if bet won and payout amount
-    let (won, base_payout, final_payout) = calculate_bet_payout(&ctx.accounts.bet, &ctx.accounts.round_accounting)?;
+    let token_decimals = ctx.accounts.betting_pool.token_decimals;
+    let (won, base_payout, final_payout) = calculate_bet_payout(
+        &ctx.accounts.bet,
+        &legs,
+        scale_token_amount(MAX_PAYOUT_PER_BET, token_decimals),
+    )?;
 
     // Slippage protection
     require!(
@@ -93,10 +149,18 @@ pub fn handler(
     ctx.accounts.bet.claimed = true;
     ctx.accounts.bet.settled = true;
 
+    release_cross_round_bet(
+        &mut ctx.accounts.betting_pool,
+        &mut ctx.accounts.round_accounting,
+        &mut other_rounds,
+        &mut ctx.accounts.bet,
+    )?;
+
     if won && final_payout > 0 {
         // Check per-round payout cap
         require!(
-            ctx.accounts.round_accounting.total_paid_out + final_payout <= MAX_ROUND_PAYOUTS,
+            ctx.accounts.round_accounting.total_paid_out + final_payout
+                <= scale_token_amount(MAX_ROUND_PAYOUTS, token_decimals),
             SportsbookError::RoundPayoutLimitReached
         );
 
@@ -168,39 +232,83 @@ pub fn handler(
 }
 
 /// Calculate bet payout with parlay multiplier
+///
+/// `results` holds each prediction's (result, settlement odds) from its resolved market.
+/// Fixed-odds legs have no settlement odds and pay at the bet's own per-leg snapshot, so
+/// later re-pricing never moves a placed bet; parimutuel legs pay at final pool odds.
+/// System bets pay every winning combination; accumulators are a single combination.
 fn calculate_bet_payout(
     bet: &Bet,
-    round_accounting: &RoundAccounting,
+    results: &[(LegResult, Option<u64>)],
+    max_payout_per_bet: u64,
 ) -> Result<(bool, u64, u64)> {
-    let mut all_correct = true;
+    let predictions = bet.get_predictions();
+    require!(results.len() == predictions.len(), SportsbookError::MarketMismatch);
+
+    let legs: Vec<(LegResult, u64)> = results
+        .iter()
+        .zip(predictions.iter())
+        .map(|(&(result, settlement_odds), prediction)| {
+            (result, settlement_odds.unwrap_or(prediction.locked_odds))
+        })
+        .collect();
+    let combinations = bet_combinations(bet)
+        .map_err(|_| SportsbookError::CalculationOverflow)?;
+
     let mut total_base_payout = 0u64;
+    let mut total_final_payout = 0u64;
+    for combination in combinations {
+        let combination_legs: Vec<(LegResult, u64)> = combination_legs(combination.legs, legs.len())
+            .into_iter()
+            .map(|leg| legs[leg])
+            .collect();
 
-    let predictions = bet.get_predictions();
+        let (base_payout, final_payout) = calculate_combination_payout(
+            &combination.allocations,
+            &combination_legs,
+            combination.multiplier,
+        )?;
+        total_base_payout += base_payout;
+        total_final_payout += final_payout;
+    }
 
-    for prediction in predictions {
-        let match_result = &round_accounting.match_results[prediction.match_index as usize];
-        let locked_odds = &round_accounting.locked_odds[prediction.match_index as usize];
+    // Cap maximum payout per bet
+    let capped_payout = if total_final_payout > max_payout_per_bet {
+        max_payout_per_bet
+    } else {
+        total_final_payout
+    };
 
-        // Check if prediction is correct
-        let predicted_outcome = match prediction.predicted_outcome {
-            1 => MatchOutcome::HomeWin,
-            2 => MatchOutcome::AwayWin,
-            3 => MatchOutcome::Draw,
-            _ => MatchOutcome::Pending,
-        };
+    Ok((capped_payout > 0, total_base_payout, capped_payout))
+}
 
-        if *match_result != predicted_outcome {
-            all_correct = false;
-            break;
+/// Payout of one accumulator combination: (base payout, payout after multiplier)
+///
+/// Legs that didn't fully win scale the whole combination by settled odds / locked odds
+/// (accumulator semantics): void legs drop out at 1.0x, quarter lines pay half.
+/// Any lost leg loses the combination.
+fn calculate_combination_payout(
+    amounts: &[u64],
+    legs: &[(LegResult, u64)],
+    multiplier: u64,
+) -> Result<(u64, u64)> {
+    let mut total_base_payout = 0u64;
+    let mut partial_legs = Vec::new();
+
+    for (&amount, &(leg_result, odds)) in amounts.iter().zip(legs.iter()) {
+        if leg_result == LegResult::Lost {
+            return Ok((0, 0));
         }
 
         // Use locked odds for payout calculation
-        require!(locked_odds.locked, SportsbookError::OddsNotLocked);
+        require!(odds > 0, SportsbookError::OddsNotLocked);
 
-        let odds = locked_odds.get_odds(prediction.predicted_outcome);
+        if leg_result != LegResult::Won {
+            partial_legs.push((leg_result.settled_odds(odds), odds));
+        }
 
         // Simple multiplication: amount × locked odds
-        let match_payout = (prediction.amount_in_pool as u128)
+        let match_payout = (amount as u128)
             .checked_mul(odds as u128)
             .ok_or(SportsbookError::CalculationOverflow)?
             .checked_div(ODDS_SCALE as u128)
@@ -209,25 +317,23 @@ fn calculate_bet_payout(
         total_base_payout += match_payout;
     }
 
-    if !all_correct {
-        return Ok((false, 0, 0));
+    // Scale by each partially settled leg
+    for (settled_odds, odds) in partial_legs {
+        total_base_payout = (total_base_payout as u128)
+            .checked_mul(settled_odds as u128)
+            .ok_or(SportsbookError::CalculationOverflow)?
+            .checked_div(odds as u128)
+            .ok_or(SportsbookError::CalculationOverflow)? as u64;
     }
 
     // Apply locked parlay multiplier
     let total_final_payout = (total_base_payout as u128)
-        .checked_mul(bet.locked_multiplier as u128)
+        .checked_mul(multiplier as u128)
         .ok_or(SportsbookError::CalculationOverflow)?
         .checked_div(ODDS_SCALE as u128)
         .ok_or(SportsbookError::CalculationOverflow)? as u64;
 
-    // Cap maximum payout per bet
-    let capped_payout = if total_final_payout > MAX_PAYOUT_PER_BET {
-        MAX_PAYOUT_PER_BET
-    } else {
-        total_final_payout
-    };
-
-    Ok((true, total_base_payout, capped_payout))
+    Ok((total_base_payout, total_final_payout))
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -236,7 +342,8 @@ fn calculate_bet_payout(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::{Bet, RoundAccounting, MatchPool, LockedOdds, MatchOutcome, Prediction};
+    use crate::state::{Bet, LockedOdds, MatchOutcome, MatchScore, Prediction, correct_score_outcome};
+    use crate::utils::{default_market_margin_bps, price_market, resolve_selection};
     use crate::constants::*;
     use anchor_lang::prelude::Pubkey;
 
@@ -245,15 +352,15 @@ mod tests {
     // ── helpers ──────────────────────────────────────────────────────────────
 
     fn make_prediction(match_index: u8, outcome: u8, amount: u64) -> Prediction {
-        Prediction { match_index, predicted_outcome: outcome, amount_in_pool: amount }
+        Prediction { round_id: 0, match_index, market_type: MARKET_MATCH_RESULT, predicted_outcome: outcome, amount_in_pool: amount, locked_odds: 0 }
+    }
+
+    fn make_totals_prediction(match_index: u8, outcome: u8, amount: u64) -> Prediction {
+        Prediction { round_id: 0, match_index, market_type: MARKET_TOTAL_GOALS, predicted_outcome: outcome, amount_in_pool: amount, locked_odds: 0 }
     }
 
-    /// Build a Bet with num_predictions slots filled from the slice.
+    /// Build a Bet with one prediction per leg in the slice.
     fn make_bet(preds: &[Prediction], multiplier: u64) -> Bet {
-        let mut arr = [Prediction { match_index: 0, predicted_outcome: 0, amount_in_pool: 0 }; 10];
-        for (i, p) in preds.iter().enumerate() {
-            arr[i] = *p;
-        }
         Bet {
             bettor: Pubkey::default(),
             round_id: 0,
@@ -264,22 +371,73 @@ mod tests {
             bonus: 0,
             locked_multiplier: multiplier,
             num_predictions: preds.len() as u8,
-            predictions: arr,
+            predictions: preds.to_vec(),
+            system_sizes: 0,
+            system_multipliers: vec![0; preds.len()],
             settled: false,
             claimed: false,
             claim_deadline: 0,
             bounty_claimer: None,
+            reserved_liability: 0,
             bump: 0,
         }
     }
 
-    /// Build a RoundAccounting with per-match results and locked odds.
-    /// results: length-10 array of MatchOutcome; odds: (home, away, draw) per match.
+    /// Settled matches for payout tests: scores plus the 1X2 odds each market is priced from.
+    struct TestRound {
+        match_scores: [MatchScore; 10],
+        locked_odds: [LockedOdds; 10],
+    }
+
+    impl TestRound {
+        /// Locked odds a leg's market would carry (fixed prices for model-priced markets)
+        fn leg_odds(&self, match_index: u8, market_type: u8, outcome: u8) -> u64 {
+            match market_type {
+                MARKET_TOTAL_GOALS => [1_900_000_000, 1_800_000_000][(outcome - 1) as usize],
+                MARKET_CORRECT_SCORE => 8_000_000_000,
+                MARKET_BOTH_TEAMS_TO_SCORE => [1_800_000_000, 1_900_000_000][(outcome - 1) as usize],
+                MARKET_ASIAN_HANDICAP => 2_000_000_000,
+                _ => price_market(
+                    market_type,
+                    &self.locked_odds[match_index as usize],
+                    default_market_margin_bps(market_type),
+                )[(outcome - 1) as usize],
+            }
+        }
+
+        /// Snapshot each leg's odds onto the bet, as place_bet does (legs already locked keep theirs)
+        fn lock(&self, bet: &Bet) -> Bet {
+            let mut bet = bet.clone();
+            let num_predictions = bet.num_predictions as usize;
+            for p in bet.predictions[..num_predictions].iter_mut() {
+                if p.locked_odds == 0 {
+                    p.locked_odds = self.leg_odds(p.match_index, p.market_type, p.predicted_outcome);
+                }
+            }
+            bet
+        }
+
+        /// (result, settlement odds) per prediction, as claim_winnings reads them from
+        /// resolved fixed-odds markets
+        fn results(&self, bet: &Bet) -> Vec<(LegResult, Option<u64>)> {
+            bet.get_predictions()
+                .iter()
+                .map(|p| {
+                    let score = &self.match_scores[p.match_index as usize];
+                    (resolve_selection(p.market_type, p.predicted_outcome, score), None)
+                })
+                .collect()
+        }
+    }
+
+    /// Build a TestRound with per-match results and locked odds.
+    /// results: length-10 array of MatchOutcome (scored 1-0, 0-1 or 0-0); odds: (home, away, draw) per match.
     fn make_round(
         results: [MatchOutcome; 10],
         odds: [(u64, u64, u64); 10],
-    ) -> RoundAccounting {
+    ) -> TestRound {
         let mut locked = [LockedOdds::default(); 10];
+        let mut scores = [MatchScore::default(); 10];
         for i in 0..10 {
             locked[i] = LockedOdds {
                 home_odds: odds[i].0,
@@ -287,32 +445,18 @@ mod tests {
                 draw_odds: odds[i].2,
                 locked: true,
             };
+            scores[i] = match results[i] {
+                MatchOutcome::HomeWin => MatchScore { home_goals: 1, away_goals: 0 },
+                MatchOutcome::AwayWin => MatchScore { home_goals: 0, away_goals: 1 },
+                _ => MatchScore::default(),
+            };
         }
-        RoundAccounting {
-            round_id: 1,
-            betting_pool: Pubkey::default(),
-            match_pools: [MatchPool::default(); 10],
-            locked_odds: locked,
-            match_results: results,
-            total_bet_volume: 0,
-            total_winning_pool: 0,
-            total_losing_pool: 0,
-            total_reserved_for_winners: 0,
-            total_claimed: 0,
-            total_paid_out: 0,
-            protocol_fee_collected: 0,
-            protocol_revenue_share: 0,
-            season_revenue_share: 0,
-            revenue_distributed: false,
-            protocol_seed_amount: 0,
-            seeded: true,
-            total_user_deposits: 0,
-            parlay_count: 0,
-            round_start_time: 0,
-            round_end_time: 0,
-            settled: true,
-            bump: 0,
-        }
+        TestRound { match_scores: scores, locked_odds: locked }
+    }
+
+    fn payout(bet: &Bet, round: &TestRound) -> Result<(bool, u64, u64)> {
+        let bet = round.lock(bet);
+        calculate_bet_payout(&bet, &round.results(&bet), MAX_PAYOUT_PER_BET)
     }
 
     fn default_results() -> [MatchOutcome; 10] {
@@ -335,7 +479,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(0, 1, 1_000_000_000)], SCALE); // 1x parlay
 
-        let (won, base_payout, final_payout) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, base_payout, final_payout) = payout(&bet, &round).unwrap();
 
         assert!(won, "Should have won");
         // payout = stake × odds = 1e9 × 1.5e9 / 1e9 = 1.5e9
@@ -343,6 +487,36 @@ mod tests {
         assert_eq!(final_payout, 1_500_000_000); // 1x multiplier
     }
 
+    #[test]
+    fn test_payout_uses_bet_odds_snapshot_after_repricing() {
+        // Bet accepted at 1.5x; the match is later re-priced to 1.2x
+        let odds = default_odds(1_200_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[0] = MatchOutcome::HomeWin;
+
+        let round = make_round(results, odds);
+        let mut leg = make_prediction(0, 1, 1_000_000_000);
+        leg.locked_odds = 1_500_000_000;
+        let bet = make_bet(&[leg], SCALE);
+
+        let (won, _, final_payout) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_payout, 1_500_000_000, "Re-pricing must not move a placed bet");
+    }
+
+    #[test]
+    fn test_parimutuel_leg_pays_at_pool_odds() {
+        // Snapshot 1.5x, but the parimutuel market settled at 3.2x
+        let mut leg = make_prediction(0, 1, 1_000_000_000);
+        leg.locked_odds = 1_500_000_000;
+        let bet = make_bet(&[leg], SCALE);
+
+        let (won, _, final_payout) =
+            calculate_bet_payout(&bet, &[(LegResult::Won, Some(3_200_000_000))], MAX_PAYOUT_PER_BET).unwrap();
+        assert!(won);
+        assert_eq!(final_payout, 3_200_000_000);
+    }
+
     #[test]
     fn test_single_bet_away_win_correct() {
         let odds = default_odds(1_400_000_000, 2_200_000_000, 1_700_000_000);
@@ -352,7 +526,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(3, 2, 500_000_000)], SCALE);
 
-        let (won, _, final_payout) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, final_payout) = payout(&bet, &round).unwrap();
         assert!(won);
         // payout = 5e8 × 2.2e9 / 1e9 = 1.1e9
         assert_eq!(final_payout, 1_100_000_000);
@@ -367,7 +541,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(7, 3, 1_000_000_000)], SCALE);
 
-        let (won, _, final_payout) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, final_payout) = payout(&bet, &round).unwrap();
         assert!(won);
         assert_eq!(final_payout, 1_700_000_000); // 1.7x
     }
@@ -383,7 +557,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(0, 1, 1_000_000_000)], SCALE); // predicted HOME
 
-        let (won, base, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, base, final_p) = payout(&bet, &round).unwrap();
         assert!(!won, "Should have lost");
         assert_eq!(base, 0);
         assert_eq!(final_p, 0);
@@ -398,7 +572,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(2, 3, 800_000_000)], SCALE); // predicted DRAW
 
-        let (won, _, _) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, _) = payout(&bet, &round).unwrap();
         assert!(!won);
     }
 
@@ -422,7 +596,7 @@ mod tests {
             make_prediction(1, 2, 400_000_000),
         ], parlay_mult);
 
-        let (won, base, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, base, final_p) = payout(&bet, &round).unwrap();
         assert!(won, "Both correct - should win");
         assert_eq!(base, 1_700_000_000, "base={}", base);
         // final = 1.7e9 × 1.05e9 / 1e9 = 1.785e9
@@ -442,7 +616,7 @@ mod tests {
             make_prediction(1, 2, 400_000_000), // correct
         ], 1_050_000_000);
 
-        let (won, _, _) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, _) = payout(&bet, &round).unwrap();
         assert!(!won, "One wrong leg → entire parlay loses");
     }
 
@@ -459,7 +633,7 @@ mod tests {
             make_prediction(1, 2, 400_000_000), // wrong
         ], 1_050_000_000);
 
-        let (won, _, _) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, _) = payout(&bet, &round).unwrap();
         assert!(!won, "Second wrong leg → entire parlay loses");
     }
 
@@ -478,7 +652,7 @@ mod tests {
 
         let bet = make_bet(&preds, 1_250_000_000); // 1.25x for 10-match parlay
 
-        let (won, base, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, base, final_p) = payout(&bet, &round).unwrap();
         assert!(won);
         assert_eq!(base, 1_500_000_000); // 10 × 100m × 1.5
         assert_eq!(final_p, 1_875_000_000); // base × 1.25
@@ -496,7 +670,7 @@ mod tests {
             .collect();
         let bet = make_bet(&preds, 1_250_000_000);
 
-        let (won, _, _) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, _) = payout(&bet, &round).unwrap();
         assert!(!won, "One wrong in 10-leg parlay → full loss");
     }
 
@@ -512,7 +686,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(0, 1, 1_000_000_000)], SCALE);
 
-        let (won, _, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
         assert!(won);
         assert_eq!(final_p, 1_200_000_000); // exactly 1.2x
     }
@@ -527,7 +701,7 @@ mod tests {
         let round = make_round(results, odds);
         let bet = make_bet(&[make_prediction(0, 2, 1_000_000_000)], SCALE);
 
-        let (won, _, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
         assert!(won);
         assert_eq!(final_p, 2_200_000_000); // exactly 2.2x
     }
@@ -553,11 +727,11 @@ mod tests {
 
         // Without boost
         let bet_no_boost = make_bet(&[make_prediction(0, 1, 1_000_000_000)], base);
-        let (_, _, p_no_boost) = calculate_bet_payout(&bet_no_boost, &round).unwrap();
+        let (_, _, p_no_boost) = payout(&bet_no_boost, &round).unwrap();
 
         // With boost
         let bet_boosted = make_bet(&[make_prediction(0, 1, 1_000_000_000)], boosted);
-        let (_, _, p_boosted) = calculate_bet_payout(&bet_boosted, &round).unwrap();
+        let (_, _, p_boosted) = payout(&bet_boosted, &round).unwrap();
 
         assert!(p_boosted > p_no_boost, "Boosted payout ({}) should exceed normal ({})", p_boosted, p_no_boost);
     }
@@ -577,10 +751,285 @@ mod tests {
             .collect();
         let bet = make_bet(&preds, 1_250_000_000); // 1.25x for 10-match parlay
 
-        let (won, _, final_p) = calculate_bet_payout(&bet, &round).unwrap();
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
         assert!(won);
         // raw payout = 10 × 10_000 tokens × 2.2 × 1.25 = 275_000 tokens
         // MAX_PAYOUT_PER_BET = 100_000 tokens — cap should be applied
         assert_eq!(final_p, MAX_PAYOUT_PER_BET, "Payout must be capped at MAX_PAYOUT_PER_BET");
     }
+
+    // ── over/under legs ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_over_leg_wins_on_three_goals() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[4] = MatchOutcome::HomeWin;
+
+        let mut round = make_round(results, odds);
+        round.match_scores[4] = MatchScore { home_goals: 2, away_goals: 1 };
+
+        let bet = make_bet(&[make_totals_prediction(4, OUTCOME_OVER, 1_000_000_000)], SCALE);
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 1_900_000_000); // locked over odds 1.9x
+
+        let bet = make_bet(&[make_totals_prediction(4, OUTCOME_UNDER, 1_000_000_000)], SCALE);
+        let (won, _, _) = payout(&bet, &round).unwrap();
+        assert!(!won, "3 goals is over the 2.5 line");
+    }
+
+    #[test]
+    fn test_parlay_mixing_1x2_and_over_under() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[0] = MatchOutcome::HomeWin;
+        results[1] = MatchOutcome::Draw;
+
+        let mut round = make_round(results, odds);
+        round.match_scores[0] = MatchScore { home_goals: 1, away_goals: 0 };
+        round.match_scores[1] = MatchScore { home_goals: 1, away_goals: 1 };
+
+        // Home win on match 0 (1.5x) + under 2.5 on match 1 (1.8x)
+        let bet = make_bet(&[
+            make_prediction(0, 1, 600_000_000),
+            make_totals_prediction(1, OUTCOME_UNDER, 500_000_000),
+        ], 1_050_000_000);
+
+        let (won, base, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        // base = 600×1.5 + 500×1.8 = 900 + 900 = 1800
+        assert_eq!(base, 1_800_000_000);
+        assert_eq!(final_p, 1_890_000_000);
+
+        // Same legs lose if match 1 went over
+        round.match_scores[1] = MatchScore { home_goals: 2, away_goals: 2 };
+        let (won, _, _) = payout(&bet, &round).unwrap();
+        assert!(!won);
+    }
+
+    // ── correct score legs ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_correct_score_grid_and_any_other() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[2] = MatchOutcome::HomeWin;
+
+        let mut round = make_round(results, odds);
+        round.match_scores[2] = MatchScore { home_goals: 2, away_goals: 1 };
+
+        let leg = |outcome| Prediction {
+            round_id: 0,
+            match_index: 2,
+            market_type: MARKET_CORRECT_SCORE,
+            predicted_outcome: outcome,
+            amount_in_pool: 100_000_000,
+            locked_odds: 0,
+        };
+
+        let (won, _, final_p) = payout(&make_bet(&[leg(correct_score_outcome(2, 1))], SCALE), &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 800_000_000); // 8x
+
+        let (won, _, _) = payout(&make_bet(&[leg(correct_score_outcome(1, 2))], SCALE), &round).unwrap();
+        assert!(!won);
+
+        // 4-1 is outside the grid, so only "any other" wins
+        round.match_scores[2] = MatchScore { home_goals: 4, away_goals: 1 };
+        let (won, _, _) = payout(&make_bet(&[leg(CORRECT_SCORE_ANY_OTHER)], SCALE), &round).unwrap();
+        assert!(won);
+    }
+
+    // ── double chance / draw no bet legs ─────────────────────────────────────
+
+    fn market_leg(match_index: u8, market_type: u8, outcome: u8, amount: u64) -> Prediction {
+        Prediction { round_id: 0, match_index, market_type, predicted_outcome: outcome, amount_in_pool: amount, locked_odds: 0 }
+    }
+
+    #[test]
+    fn test_double_chance_wins_on_either_covered_outcome() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[0] = MatchOutcome::Draw;
+        results[1] = MatchOutcome::HomeWin;
+        let round = make_round(results, odds);
+
+        let dc_odds = round.leg_odds(0, MARKET_DOUBLE_CHANCE, OUTCOME_HOME_OR_DRAW);
+        let bet = make_bet(&[
+            market_leg(0, MARKET_DOUBLE_CHANCE, OUTCOME_HOME_OR_DRAW, 1_000_000_000),
+            market_leg(1, MARKET_DOUBLE_CHANCE, OUTCOME_HOME_OR_DRAW, 1_000_000_000),
+        ], SCALE);
+
+        let (won, base, _) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(base, 2 * dc_odds);
+
+        let bet = make_bet(&[market_leg(0, MARKET_DOUBLE_CHANCE, OUTCOME_HOME_OR_AWAY, 1_000_000_000)], SCALE);
+        let (won, _, _) = payout(&bet, &round).unwrap();
+        assert!(!won, "12 loses on a draw");
+    }
+
+    #[test]
+    fn test_draw_no_bet_refunds_on_draw() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[0] = MatchOutcome::Draw;
+        results[1] = MatchOutcome::HomeWin;
+        let round = make_round(results, odds);
+
+        // Single DNB on a draw returns the stake
+        let bet = make_bet(&[market_leg(0, MARKET_DRAW_NO_BET, 1, 1_000_000_000)], SCALE);
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 1_000_000_000);
+
+        // In a parlay the void leg drops out: the bet pays as if it were the other leg alone
+        let void_odds = round.leg_odds(0, MARKET_DRAW_NO_BET, 2);
+        let dnb_odds = round.leg_odds(1, MARKET_DRAW_NO_BET, 1);
+        let bet = make_bet(&[
+            market_leg(0, MARKET_DRAW_NO_BET, 2, 500_000_000),
+            market_leg(1, MARKET_DRAW_NO_BET, 1, 500_000_000),
+        ], SCALE);
+        let (won, base, _) = payout(&bet, &round).unwrap();
+        assert!(won);
+        let full = 500_000_000 * void_odds / SCALE + 500_000_000 * dnb_odds / SCALE;
+        assert_eq!(base, full * SCALE / void_odds);
+
+        // Wrong side loses
+        let bet = make_bet(&[market_leg(1, MARKET_DRAW_NO_BET, 2, 1_000_000_000)], SCALE);
+        let (won, _, _) = payout(&bet, &round).unwrap();
+        assert!(!won);
+    }
+
+    // ── both teams to score / Asian handicap legs ─────────────────────────────
+
+    /// Asian handicap outcome for a home line in quarter goals (away backs the opposite line)
+    fn handicap_outcome(line: i8, home: bool) -> u8 {
+        let line_index = ASIAN_HANDICAP_LINES.iter().position(|&l| l == line).unwrap() as u8;
+        1 + line_index * 2 + if home { 0 } else { 1 }
+    }
+
+    #[test]
+    fn test_btts_leg() {
+        let mut round = make_round(default_results(), default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000));
+        round.match_scores[0] = MatchScore { home_goals: 1, away_goals: 1 };
+
+        let bet = make_bet(&[market_leg(0, MARKET_BOTH_TEAMS_TO_SCORE, OUTCOME_BTTS_YES, 1_000_000_000)], SCALE);
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 1_800_000_000);
+
+        let bet = make_bet(&[market_leg(0, MARKET_BOTH_TEAMS_TO_SCORE, OUTCOME_BTTS_NO, 1_000_000_000)], SCALE);
+        let (won, _, _) = payout(&bet, &round).unwrap();
+        assert!(!won);
+    }
+
+    #[test]
+    fn test_asian_handicap_quarter_lines() {
+        let mut round = make_round(default_results(), default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000));
+        // Home wins by exactly one goal
+        round.match_scores[0] = MatchScore { home_goals: 2, away_goals: 1 };
+        let stake = 1_000_000_000;
+
+        let settle = |line: i8, home: bool| {
+            let bet = make_bet(&[market_leg(0, MARKET_ASIAN_HANDICAP, handicap_outcome(line, home), stake)], SCALE);
+            payout(&bet, &round).unwrap().2
+        };
+
+        // Home -0.5: win at 2.0x
+        assert_eq!(settle(-2, true), 2_000_000_000);
+        // Home -1: push, stake back
+        assert_eq!(settle(-4, true), stake);
+        // Home -0.75: half on -0.5 (win), half on -1 (push) → (2.0 + 1.0) / 2
+        assert_eq!(settle(-3, true), 1_500_000_000);
+        // Away +0.75: half on +0.5 (lose), half on +1 (push) → half stake back
+        assert_eq!(settle(-3, false), 500_000_000);
+        // Away +0.5: loses outright
+        assert_eq!(settle(-2, false), 0);
+        // Away +1.0 (home line -1): push
+        assert_eq!(settle(-4, false), stake);
+    }
+
+    #[test]
+    fn test_half_win_scales_whole_parlay() {
+        let odds = default_odds(1_500_000_000, 2_000_000_000, 1_800_000_000);
+        let mut results = default_results();
+        results[1] = MatchOutcome::HomeWin;
+        let mut round = make_round(results, odds);
+        round.match_scores[0] = MatchScore { home_goals: 2, away_goals: 1 };
+
+        // Home -0.75 (half win) + home win at 1.5x
+        let bet = make_bet(&[
+            market_leg(0, MARKET_ASIAN_HANDICAP, handicap_outcome(-3, true), 600_000_000),
+            make_prediction(1, 1, 800_000_000),
+        ], SCALE);
+
+        let (won, base, _) = payout(&bet, &round).unwrap();
+        assert!(won);
+        // full = 600×2.0 + 800×1.5 = 2400, scaled by 1.5 / 2.0
+        assert_eq!(base, 1_800_000_000);
+    }
+
+    // ── system bets ───────────────────────────────────────────────────────────
+
+    fn make_system_bet(legs: &[(u8, u8)], system_sizes: u16, amount_after_fee: u64) -> Bet {
+        let preds: Vec<_> = legs.iter().map(|&(m, o)| make_prediction(m, o, 0)).collect();
+        let mut bet = make_bet(&preds, SCALE);
+        bet.amount_after_fee = amount_after_fee;
+        bet.system_sizes = system_sizes;
+        bet.system_multipliers = vec![SCALE; preds.len()];
+        bet
+    }
+
+    #[test]
+    fn test_trixie_pays_surviving_double() {
+        // Three legs at 2.0x home; match 2 goes away
+        let odds = default_odds(2_000_000_000, 2_000_000_000, 2_000_000_000);
+        let mut results = default_results();
+        results[0] = MatchOutcome::HomeWin;
+        results[1] = MatchOutcome::HomeWin;
+        results[2] = MatchOutcome::AwayWin;
+        let round = make_round(results, odds);
+
+        // 4 combinations × 250: only the 0+1 double wins, 250 × 2.0 × 2.0
+        let bet = make_system_bet(&[(0, 1), (1, 1), (2, 1)], SYSTEM_TRIXIE, 1_000_000_000);
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 1_000_000_000);
+
+        // Two losing legs leave no winning double
+        let mut round = round;
+        round.match_scores[1] = MatchScore { home_goals: 0, away_goals: 2 };
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(!won);
+        assert_eq!(final_p, 0);
+    }
+
+    #[test]
+    fn test_lucky_15_all_winning() {
+        let odds = default_odds(2_000_000_000, 2_000_000_000, 2_000_000_000);
+        let results = [MatchOutcome::HomeWin; 10];
+        let round = make_round(results, odds);
+
+        // 15 combinations × 1200: 4 singles at 2x, 6 doubles at 4x, 4 trebles at 8x, 1 four-fold at 16x
+        let bet = make_system_bet(&[(0, 1), (1, 1), (2, 1), (3, 1)], SYSTEM_LUCKY_15, 15 * 1_200);
+        let (won, _, final_p) = payout(&bet, &round).unwrap();
+        assert!(won);
+        assert_eq!(final_p, 1_200 * (4 * 2 + 6 * 4 + 4 * 8 + 16));
+    }
+
+    // ── cross-round accumulators ──────────────────────────────────────────────
+
+    #[test]
+    fn test_cross_round_legs_reference_each_other_round_once() {
+        let mut legs = [make_prediction(0, 1, 100), make_prediction(3, 1, 100), make_prediction(0, 2, 100), make_prediction(5, 1, 100)];
+        assert!(make_bet(&legs, SCALE).other_rounds().is_empty());
+
+        // Same match index in different rounds is a different match
+        legs[1].round_id = 8;
+        legs[2].round_id = 9;
+        legs[3].round_id = 8;
+        assert_eq!(make_bet(&legs, SCALE).other_rounds(), vec![8, 9]);
+    }
 }
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::state::{BettingPool, RoundAccounting, Market, MarketSelection, OddsProfile};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::{market_outcome_count, price_market, market_liability_cap, default_market_margin_bps, overround_to_margin_bps};
+
+#[derive(Accounts)]
+#[instruction(round_id: u64, match_index: u8, market_type: u8)]
+pub struct CreateMarket<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = round_accounting.seeded @ SportsbookError::RoundNotSeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Market::LEN,
+        seeds = [
+            b"market",
+            betting_pool.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+            match_index.to_le_bytes().as_ref(),
+            market_type.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// Optional: house odds profile (defaults to the standard per-market margins)
+    #[account(
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump = odds_profile.bump,
+    )]
+    pub odds_profile: Option<Box<Account<'info, OddsProfile>>>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateMarket>,
+    round_id: u64,
+    match_index: u8,
+    market_type: u8,
+) -> Result<()> {
+    require!(
+        match_index < ctx.accounts.round_accounting.num_matches,
+        SportsbookError::InvalidMatchIndex
+    );
+
+    let num_selections = market_outcome_count(market_type);
+    require!(num_selections > 0, SportsbookError::InvalidMarketType);
+
+    let locked_odds = ctx.accounts.round_accounting.locked_odds[match_index as usize];
+    require!(locked_odds.locked, SportsbookError::OddsNotLocked);
+
+    let margin_bps = match ctx.accounts.odds_profile {
+        Some(ref profile) => overround_to_margin_bps(profile.overround_bps[market_type as usize]),
+        None => default_market_margin_bps(market_type),
+    };
+
+    // Every market is priced from the match's locked 1X2 odds
+    let prices = price_market(market_type, &locked_odds, margin_bps);
+
+    let market = &mut ctx.accounts.market;
+    market.betting_pool = ctx.accounts.betting_pool.key();
+    market.round_id = round_id;
+    market.match_index = match_index;
+    market.market_type = market_type;
+    market.num_selections = num_selections;
+    market.selections = [MarketSelection::default(); MAX_MARKET_SELECTIONS];
+    market.total_pool = 0;
+    market.max_liability = market_liability_cap(market_type, ctx.accounts.betting_pool.token_decimals);
+    market.seed_liquidity = ctx.accounts.round_accounting.protocol_seed_amount / ctx.accounts.round_accounting.num_matches as u64;
+    market.resolved = false;
+    market.bump = ctx.bumps.market;
+
+    // Parimutuel markets carry no house liability, so there's nothing to cap or reprice
+    let parimutuel = ctx.accounts.round_accounting.pricing_mode == PRICING_PARIMUTUEL;
+    market.parimutuel = parimutuel;
+    if parimutuel {
+        market.max_liability = 0;
+        market.reprice_band_bps = 0;
+    } else {
+        market.reprice_band_bps = ctx.accounts.betting_pool.reprice_band_bps;
+    }
+
+    for (selection, &odds) in market.selections.iter_mut().zip(prices.iter()) {
+        selection.odds = odds;
+        selection.seed_odds = odds;
+    }
+
+    // The match result market carries the round's seed liquidity
+    if market_type == MARKET_MATCH_RESULT {
+        let seed_pool = ctx.accounts.round_accounting.match_pools[match_index as usize];
+        for outcome in 1..=num_selections {
+            market.seed_stake(outcome, seed_pool.get_pool_amount(outcome))?;
+        }
+    }
+
+    msg!(
+        "Market created: round {}, match {}, type {}, {} selections",
+        round_id,
+        match_index,
+        market_type,
+        num_selections
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRepriceBand<'info> {
+    #[account(mut)]
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Set the repricing band for markets created from now on (existing markets keep theirs)
+pub fn set_reprice_band_handler(ctx: Context<SetRepriceBand>, band_bps: u16) -> Result<()> {
+    require!(
+        band_bps <= MAX_REPRICE_BAND_BPS,
+        SportsbookError::RepriceBandTooHigh
+    );
+
+    ctx.accounts.betting_pool.reprice_band_bps = band_bps;
+
+    msg!("Reprice band set to {}bps", band_bps);
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::state::{BettingPool, RoundAccounting, Bet, Prediction, Market};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::{calculate_max_payout, is_valid_system, scale_token_amount};
+use super::cash_out::load_bet_markets;
+use super::place_bet::{holds_team_tokens, price_legs};
+
+#[derive(Accounts)]
+#[instruction(bet_id: u64)]
+pub struct EditBet<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        seeds = [b"round", betting_pool.key().as_ref(), bet.round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [b"bet", betting_pool.key().as_ref(), bet_id.to_le_bytes().as_ref()],
+        bump = bet.bump,
+        has_one = bettor @ SportsbookError::NotBettor,
+    )]
+    pub bet: Box<Account<'info, Bet>>,
+
+    /// Betting pool's token account (must cover the edited bet's max payout)
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Optional: User's team token account (keeps the odds boost on the new legs)
+    pub team_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Optional: Mint of the team token account (its decimals scale the minimum balance)
+    pub team_token_mint: Option<Box<Account<'info, Mint>>>,
+
+    pub bettor: Signer<'info>,
+
+    // Remaining accounts: one writable Market per current prediction, in prediction order,
+    // followed by the replacement leg's writable Market when `new_outcome` is set
+}
+
+/// Remove a leg from a bet, or replace it with a selection in another market
+///
+/// The whole stake is pulled out of every leg's market and re-placed across the
+/// new leg set at current prices, with the parlay multiplier re-locked at current imbalances
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EditBet<'info>>,
+    bet_id: u64,
+    leg_index: u8,
+    new_outcome: Option<u8>,
+) -> Result<()> {
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let round_id = ctx.accounts.bet.round_id;
+    let system_sizes = ctx.accounts.bet.system_sizes;
+    let amount_after_fee = ctx.accounts.bet.amount_after_fee;
+    let predictions = ctx.accounts.bet.get_predictions().to_vec();
+    let leg_index = leg_index as usize;
+    require!(leg_index < predictions.len(), SportsbookError::InvalidLegIndex);
+    require!(ctx.accounts.bet.other_rounds().is_empty(), SportsbookError::InvalidCrossRoundBet);
+
+    // Every leg's stake is re-placed at current prices, so every leg's match must still
+    // be open (past its cutoff or kickoff that would be in-play repricing)
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        predictions
+            .iter()
+            .all(|p| ctx.accounts.round_accounting.match_open(p.match_index as usize, now)),
+        SportsbookError::BettingClosed
+    );
+
+    let expected_accounts = predictions.len() + new_outcome.map_or(0, |_| 1);
+    require!(
+        ctx.remaining_accounts.len() == expected_accounts,
+        SportsbookError::MarketMismatch
+    );
+
+    // Pull the bet's stake out of every market it was placed in
+    let mut markets = load_bet_markets(
+        &ctx.remaining_accounts[..predictions.len()],
+        &ctx.accounts.bet,
+        betting_pool_key,
+    )?;
+    for (prediction, market) in predictions.iter().zip(markets.iter_mut()) {
+        market.remove_stake(prediction.predicted_outcome, prediction.amount_in_pool, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+    }
+
+    // Build the new leg set in place of the edited leg
+    markets.remove(leg_index);
+    let mut outcomes: Vec<u8> = predictions.iter().map(|p| p.predicted_outcome).collect();
+    outcomes.remove(leg_index);
+
+    if let Some(outcome) = new_outcome {
+        // Loaded after the removals are written, so replacing a selection in the
+        // same market sees the stake already pulled out
+        let market_info = &ctx.remaining_accounts[predictions.len()];
+        require!(market_info.is_writable, SportsbookError::MarketMismatch);
+        let market: Account<Market> = Account::try_from(market_info)?;
+
+        require!(
+            market.betting_pool == betting_pool_key && market.round_id == round_id,
+            SportsbookError::MarketMismatch
+        );
+        require!(market.odds(outcome) > 0, SportsbookError::InvalidOutcome);
+        require!(
+            ctx.accounts.round_accounting.match_open(market.match_index as usize, now),
+            SportsbookError::BettingClosed
+        );
+        require!(
+            !markets.iter().any(|m| m.match_index == market.match_index),
+            SportsbookError::DuplicateMatchInBet
+        );
+
+        markets.insert(leg_index, market);
+        outcomes.insert(leg_index, outcome);
+    }
+
+    require!(!markets.is_empty(), SportsbookError::InvalidBetCount);
+    require!(
+        is_valid_system(markets.len() as u8, system_sizes),
+        SportsbookError::InvalidSystemBet
+    );
+
+    let has_team_tokens = holds_team_tokens(&ctx.accounts.team_token_account, &ctx.accounts.team_token_mint);
+
+    let parimutuel = ctx.accounts.round_accounting.pricing_mode == PRICING_PARIMUTUEL;
+    let pricing = price_legs(&markets, &outcomes, system_sizes, amount_after_fee, has_team_tokens, parimutuel)?;
+
+    // Re-place the stake across the new legs
+    // (never more legs than before, so the bet account still fits them)
+    let mut new_predictions = Vec::with_capacity(markets.len());
+
+    for (i, market) in markets.iter_mut().enumerate() {
+        let outcome = outcomes[i];
+        let allocation = pricing.allocations[i];
+
+        let prediction = Prediction {
+            round_id: market.round_id,
+            match_index: market.match_index,
+            market_type: market.market_type,
+            predicted_outcome: outcome,
+            amount_in_pool: allocation,
+            locked_odds: market.odds(outcome),
+        };
+
+        market.add_stake(outcome, allocation, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+        new_predictions.push(prediction);
+    }
+
+    let bet = &mut ctx.accounts.bet;
+    bet.predictions = new_predictions;
+    bet.num_predictions = markets.len() as u8;
+    bet.allocated_amount = pricing.total_allocated;
+    bet.locked_multiplier = pricing.multiplier;
+    bet.system_multipliers = pricing.system_multipliers;
+
+    // The edited bet's max payout, at the new legs' locked odds, must be covered
+    if !parimutuel {
+        let max_possible_payout = calculate_max_payout(
+            bet,
+            scale_token_amount(MAX_PAYOUT_PER_BET, ctx.accounts.betting_pool.token_decimals),
+        )
+        .map_err(|_| SportsbookError::CalculationOverflow)?;
+        require!(
+            ctx.accounts.betting_pool_token_account.amount
+                >= max_possible_payout
+                    .saturating_add(ctx.accounts.betting_pool.cross_round_liability)
+                    .saturating_add(ctx.accounts.betting_pool.outright_reserve),
+            SportsbookError::InsufficientProtocolLiquidity
+        );
+    }
+
+    msg!("Bet {} edited: leg {} {}", bet_id, leg_index, if new_outcome.is_some() { "replaced" } else { "removed" });
+    msg!("Legs: {}, parlay multiplier: {}", markets.len(), pricing.multiplier);
+
+    Ok(())
+}
@@ -1,14 +1,45 @@
 use anchor_lang::prelude::*;
-use crate::state::BettingPool;
+use crate::state::{BettingPool, SeasonOutright, SeasonResult};
 use crate::errors::SportsbookError;
+use crate::utils::claimable_season_rewards;
 
 #[derive(Accounts)]
 pub struct EndSeason<'info> {
     #[account(mut)]
     pub betting_pool: Account<'info, BettingPool>,
 
+    /// The season's outright market (created empty if nobody staked, so the winner is always recorded)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SeasonOutright::LEN,
+        seeds = [
+            b"season_outright",
+            betting_pool.key().as_ref(),
+            betting_pool.current_season_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub season_outright: Box<Account<'info, SeasonOutright>>,
+
+    /// Snapshot of the season's winner and prediction weights (season rewards are paid from it)
+    #[account(
+        init,
+        payer = authority,
+        space = SeasonResult::LEN,
+        seeds = [
+            b"season_result",
+            betting_pool.key().as_ref(),
+            betting_pool.current_season_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub season_result: Box<Account<'info, SeasonResult>>,
+
     #[account(mut, constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -31,9 +62,48 @@ pub fn handler(
     ctx.accounts.betting_pool.season_ended = true;
     ctx.accounts.betting_pool.season_winning_team = winning_team;
 
+    // Record the winner on the season's outright market and release the reserve
+    // held for the teams that didn't win
+    let season_id = ctx.accounts.betting_pool.current_season_id;
+    let outright = &mut ctx.accounts.season_outright;
+    if outright.betting_pool == Pubkey::default() {
+        outright.betting_pool = ctx.accounts.betting_pool.key();
+        outright.season_id = season_id;
+        outright.bump = ctx.bumps.season_outright;
+    }
+    outright.settled = true;
+    outright.winning_team = winning_team;
+    outright.update_reserve(&mut ctx.accounts.betting_pool.outright_reserve);
+
+    // Snapshot the pool so every correct predictor's share is computed from the same base
+    // (rewards still owed to earlier seasons aren't this season's to give out)
+    let betting_pool = &mut ctx.accounts.betting_pool;
+    betting_pool.season_reward_snapshot = betting_pool
+        .season_reward_pool
+        .saturating_sub(betting_pool.season_rewards_committed);
+
+    // Commit what this season's predictors can claim; anything unclaimable carries over
+    let team_weights = betting_pool.season_team_weights;
+    let unclaimed_rewards = claimable_season_rewards(
+        betting_pool.season_reward_snapshot,
+        team_weights[winning_team as usize],
+        team_weights.iter().sum(),
+    );
+    betting_pool.season_rewards_committed += unclaimed_rewards;
+
+    // Keep the winner and weights per season: the pool's copies reset when the next season starts
+    let season_result = &mut ctx.accounts.season_result;
+    season_result.season_id = season_id;
+    season_result.winning_team = winning_team;
+    season_result.team_weights = team_weights;
+    season_result.reward_snapshot = betting_pool.season_reward_snapshot;
+    season_result.unclaimed_rewards = unclaimed_rewards;
+    season_result.bump = ctx.bumps.season_result;
+
     msg!("Season {} ended!", ctx.accounts.betting_pool.current_season_id);
     msg!("Winning team: {}", winning_team);
-    msg!("Season reward pool: {}", ctx.accounts.betting_pool.season_reward_pool);
+    msg!("Season reward pool: {}", ctx.accounts.betting_pool.season_reward_snapshot);
+    msg!("Committed to predictors: {}", unclaimed_rewards);
 
     Ok(())
 }
@@ -49,6 +119,7 @@ pub struct StartNewSeason<'info> {
 
 pub fn start_new_season_handler(
     ctx: Context<StartNewSeason>,
+    prediction_cutoff_rounds: u64,
 ) -> Result<()> {
     // Ensure previous season has ended
     require!(
@@ -56,13 +127,23 @@ pub fn start_new_season_handler(
         SportsbookError::RoundNotSettled
     );
 
+    require!(
+        prediction_cutoff_rounds > 0,
+        SportsbookError::InvalidAmount
+    );
+
     // Increment season ID and reset state
     ctx.accounts.betting_pool.current_season_id += 1;
     ctx.accounts.betting_pool.season_ended = false;
     ctx.accounts.betting_pool.season_winning_team = 0;
-    // Note: season_reward_pool carries over to new season
+    ctx.accounts.betting_pool.season_start_round_id = ctx.accounts.betting_pool.next_round_id;
+    ctx.accounts.betting_pool.prediction_cutoff_rounds = prediction_cutoff_rounds;
+    ctx.accounts.betting_pool.season_team_weights = [0; 10];
+    ctx.accounts.betting_pool.season_reward_snapshot = 0;
+    // Note: season_reward_pool carries over to new season (less what ended seasons still owe)
 
     msg!("New season started: {}", ctx.accounts.betting_pool.current_season_id);
+    msg!("Predictions close after {} rounds", prediction_cutoff_rounds);
 
     Ok(())
 }
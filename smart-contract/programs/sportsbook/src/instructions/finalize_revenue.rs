@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{BettingPool, RoundAccounting};
 use crate::errors::SportsbookError;
-use crate::constants::*;
+use crate::utils::calculate_season_share;
 
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
@@ -16,6 +16,8 @@ pub struct FinalizeRoundRevenue<'info> {
         bump = round_accounting.bump,
         constraint = round_accounting.settled @ SportsbookError::RoundNotSettled,
         constraint = !round_accounting.revenue_distributed @ SportsbookError::RevenueAlreadyDistributed,
+        // Cross-round bets close when claimed or marked dead (see release_cross_round_bet)
+        constraint = round_accounting.open_cross_round_bets == 0 @ SportsbookError::CrossRoundBetsOpen,
     )]
     pub round_accounting: Account<'info, RoundAccounting>,
 
@@ -23,6 +25,14 @@ pub struct FinalizeRoundRevenue<'info> {
     #[account(mut)]
     pub betting_pool_token_account: Account<'info, TokenAccount>,
 
+    /// Season vault (receives this round's season share)
+    #[account(
+        mut,
+        seeds = [b"season_vault", betting_pool.key().as_ref()],
+        bump,
+    )]
+    pub season_vault: Account<'info, TokenAccount>,
+
     #[account(mut, constraint = authority.key() == betting_pool.authority)]
     pub authority: Signer<'info>,
 
@@ -60,6 +70,10 @@ pub fn handler(ctx: Context<FinalizeRoundRevenue>, round_id: u64) -> Result<()>
 
     // Check actual balance remaining in betting pool
     let remaining_in_contract = ctx.accounts.betting_pool_token_account.amount;
+
+    // Payouts reserved for open cross-round bets and outright markets stay betting liquidity
+    let reserved = ctx.accounts.betting_pool.cross_round_liability
+        .saturating_add(ctx.accounts.betting_pool.outright_reserve);
     let protocol_seed = ctx.accounts.round_accounting.protocol_seed_amount;
     let user_deposits = ctx.accounts.round_accounting.total_user_deposits;
     let total_paid = ctx.accounts.round_accounting.total_paid_out;
@@ -75,23 +89,34 @@ pub fn handler(ctx: Context<FinalizeRoundRevenue>, round_id: u64) -> Result<()>
     let mut operating_profit = 0i64; // Can be negative!
 
     if user_deposits > 0 {
-        // Season pool gets exactly 2% of ACTUAL USER DEPOSITS (before fee)
-        let total_user_bets_before_fee = user_deposits
-            .saturating_add(ctx.accounts.round_accounting.protocol_fee_collected);
-
-        season_share = (total_user_bets_before_fee as u128)
-            .checked_mul(season_pool_share_bps as u128)
-            .ok_or(SportsbookError::CalculationOverflow)?
-            .checked_div(BPS_DENOMINATOR as u128)
-            .ok_or(SportsbookError::CalculationOverflow)? as u64;
-
-        // Cap season share to what's actually available
-        if season_share > remaining_in_contract {
-            season_share = remaining_in_contract;
-        }
-
-        // Allocate season pool share (stays in betting pool for season rewards)
+        // Season pool gets exactly 2% of ACTUAL USER DEPOSITS (before fee),
+        // capped to the round's profit and to what isn't reserved for open bets
+        season_share = calculate_season_share(
+            user_deposits,
+            ctx.accounts.round_accounting.protocol_fee_collected,
+            total_paid,
+            season_pool_share_bps,
+            remaining_in_contract.saturating_sub(reserved),
+        )
+        .ok_or(SportsbookError::CalculationOverflow)?;
+
+        // Move season pool share out of betting liquidity into the season vault
         if season_share > 0 {
+            let seeds = &[
+                b"betting_pool".as_ref(),
+                &[ctx.accounts.betting_pool.bump],
+            ];
+            let signer = &[&seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.betting_pool_token_account.to_account_info(),
+                to: ctx.accounts.season_vault.to_account_info(),
+                authority: ctx.accounts.betting_pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+            token::transfer(cpi_ctx, season_share)?;
+
             ctx.accounts.betting_pool.season_reward_pool += season_share;
         }
     }
@@ -119,7 +144,7 @@ pub fn handler(ctx: Context<FinalizeRoundRevenue>, round_id: u64) -> Result<()>
     msg!("Total paid: {}", total_paid);
     msg!("Operating profit: {} (negative = loss from seed)", operating_profit);
     msg!("Remaining balance: {}", remaining_in_contract);
-    msg!("Season share: {}", season_share);
+    msg!("Season share: {} (moved to season vault)", season_share);
 
     Ok(())
 }
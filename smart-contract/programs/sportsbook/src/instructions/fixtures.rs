@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+use crate::state::{BettingPool, RoundAccounting, OracleSet, Fixture, MatchScore};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::ed25519_signers;
+use super::results::{proposal_mask, write_results};
+
+#[derive(Accounts)]
+pub struct ConfigureOracleSet<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = OracleSet::LEN,
+        seeds = [b"oracle_set", betting_pool.key().as_ref()],
+        bump
+    )]
+    pub oracle_set: Account<'info, OracleSet>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the results oracle keys and the M-of-N signature threshold
+pub fn configure_oracle_set_handler(
+    ctx: Context<ConfigureOracleSet>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_ORACLE_SIGNERS,
+        SportsbookError::InvalidOracleSet
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        SportsbookError::InvalidOracleSet
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), SportsbookError::InvalidOracleSet);
+    }
+
+    let oracle_set = &mut ctx.accounts.oracle_set;
+    oracle_set.betting_pool = ctx.accounts.betting_pool.key();
+    oracle_set.signers = [Pubkey::default(); MAX_ORACLE_SIGNERS];
+    oracle_set.signers[..signers.len()].copy_from_slice(&signers);
+    oracle_set.num_signers = signers.len() as u8;
+    oracle_set.threshold = threshold;
+    oracle_set.bump = ctx.bumps.oracle_set;
+
+    msg!("Oracle set configured: {}-of-{}", threshold, signers.len());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct RegisterFixtures<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.seeded @ SportsbookError::RoundAlreadySeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Turn a round into a real-world fixture round (before it is seeded)
+///
+/// Each match's betting closes at its kickoff, and the round settles only from
+/// oracle-attested results instead of VRF randomness or the authority
+pub fn register_fixtures_handler(
+    ctx: Context<RegisterFixtures>,
+    round_id: u64,
+    fixtures: Vec<Fixture>,
+) -> Result<()> {
+    require!(
+        fixtures.len() == ctx.accounts.round_accounting.num_matches as usize,
+        SportsbookError::ArrayLengthMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    for (i, fixture) in fixtures.iter().enumerate() {
+        require!(fixture.kickoff_time > now, SportsbookError::InvalidFixtures);
+        require!(
+            !fixtures[..i].iter().any(|f| f.fixture_id == fixture.fixture_id),
+            SportsbookError::InvalidFixtures
+        );
+    }
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    round_accounting.round_kind = ROUND_FIXTURES;
+    round_accounting.fixtures.copy_from_slice(&fixtures);
+
+    msg!("Round {}: {} fixtures registered", round_id, fixtures.len());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct AttestFixtureResults<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = round_accounting.round_kind == ROUND_FIXTURES @ SportsbookError::InvalidRoundKind,
+        constraint = round_accounting.seeded @ SportsbookError::RoundNotSeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        seeds = [b"oracle_set", betting_pool.key().as_ref()],
+        bump = oracle_set.bump,
+    )]
+    pub oracle_set: Account<'info, OracleSet>,
+
+    /// Instructions sysvar (the oracles' ed25519 signatures are the preceding instruction)
+    /// CHECK: address is checked against the instructions sysvar ID
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Anyone may relay an attestation the oracles signed
+    pub relayer: Signer<'info>,
+}
+
+/// Propose results for some of a fixture round's matches, signed by at least `threshold` oracle keys
+///
+/// The transaction must carry an ed25519 program instruction, immediately before this
+/// one, with the oracles' signatures over `fixture_results_message(...)`. Like any
+/// proposal the results settle their matches only after the challenge period (finalize_results).
+pub fn attest_fixture_results_handler(
+    ctx: Context<AttestFixtureResults>,
+    round_id: u64,
+    match_indices: Vec<u8>,
+    match_scores: Vec<MatchScore>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.round_accounting.results_proposed_at == 0,
+        SportsbookError::ResultsAlreadyProposed
+    );
+    let proposed_matches = proposal_mask(&ctx.accounts.round_accounting, &match_indices, &match_scores)?;
+
+    // Results can only exist for matches that have kicked off
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        match_indices
+            .iter()
+            .all(|&i| ctx.accounts.round_accounting.match_started(i as usize, now)),
+        SportsbookError::InvalidFixtures
+    );
+
+    let fixtures: Vec<Fixture> = match_indices
+        .iter()
+        .map(|&i| ctx.accounts.round_accounting.fixtures[i as usize])
+        .collect();
+    let message = fixture_results_message(
+        &ctx.accounts.betting_pool.key(),
+        round_id,
+        &fixtures,
+        &match_scores,
+    );
+    let signers = ed25519_signers(&ctx.accounts.instructions_sysvar, &message)?;
+    let attestations = ctx.accounts.oracle_set.count_attestations(&signers);
+    require!(
+        attestations >= ctx.accounts.oracle_set.threshold as usize,
+        SportsbookError::AttestationThresholdNotMet
+    );
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    write_results(round_accounting, &match_indices, &match_scores);
+    round_accounting.proposed_matches = proposed_matches;
+    round_accounting.results_proposed_at = now;
+
+    msg!(
+        "Round {}: results for {} matches attested by {} of {} oracles, disputable for {}s",
+        round_id,
+        match_indices.len(),
+        attestations,
+        ctx.accounts.oracle_set.num_signers,
+        ctx.accounts.betting_pool.challenge_period
+    );
+
+    Ok(())
+}
+
+/// Payload the results oracles sign:
+/// prefix ‖ betting pool ‖ round id ‖ (fixture id, home goals, away goals) per attested match
+pub fn fixture_results_message(
+    betting_pool: &Pubkey,
+    round_id: u64,
+    fixtures: &[Fixture],
+    match_scores: &[MatchScore],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(FIXTURE_RESULTS_MESSAGE_PREFIX.len() + 40 + match_scores.len() * 10);
+    message.extend_from_slice(FIXTURE_RESULTS_MESSAGE_PREFIX);
+    message.extend_from_slice(betting_pool.as_ref());
+    message.extend_from_slice(&round_id.to_le_bytes());
+    for (fixture, score) in fixtures.iter().zip(match_scores.iter()) {
+        message.extend_from_slice(&fixture.fixture_id.to_le_bytes());
+        message.push(score.home_goals);
+        message.push(score.away_goals);
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle_set(signers: &[Pubkey], threshold: u8) -> OracleSet {
+        let mut set = OracleSet {
+            betting_pool: Pubkey::default(),
+            signers: [Pubkey::default(); MAX_ORACLE_SIGNERS],
+            num_signers: signers.len() as u8,
+            threshold,
+            bump: 0,
+        };
+        set.signers[..signers.len()].copy_from_slice(signers);
+        set
+    }
+
+    #[test]
+    fn test_attestations_count_distinct_oracle_keys() {
+        let oracles: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let set = oracle_set(&oracles, 2);
+        let outsider = Pubkey::new_unique();
+
+        assert_eq!(set.count_attestations(&[oracles[0]]), 1);
+        assert_eq!(set.count_attestations(&[oracles[0], oracles[2]]), 2);
+
+        // Repeated signatures from one key and keys outside the set don't count
+        assert_eq!(set.count_attestations(&[oracles[1], oracles[1], outsider]), 1);
+        assert_eq!(set.count_attestations(&[]), 0);
+    }
+
+    #[test]
+    fn test_fixture_results_message_binds_fixtures_and_scores() {
+        let pool = Pubkey::new_unique();
+        let fixtures = [Fixture { fixture_id: 1001, kickoff_time: 0 }, Fixture { fixture_id: 1002, kickoff_time: 0 }];
+        let scores = [MatchScore { home_goals: 2, away_goals: 1 }, MatchScore { home_goals: 0, away_goals: 0 }];
+
+        let message = fixture_results_message(&pool, 7, &fixtures, &scores);
+        assert!(message.starts_with(FIXTURE_RESULTS_MESSAGE_PREFIX));
+        assert_eq!(message.len(), FIXTURE_RESULTS_MESSAGE_PREFIX.len() + 32 + 8 + 2 * 10);
+
+        // A different score, fixture or round is a different payload
+        let flipped = [MatchScore { home_goals: 1, away_goals: 2 }, scores[1]];
+        assert_ne!(message, fixture_results_message(&pool, 7, &fixtures, &flipped));
+        assert_ne!(message, fixture_results_message(&pool, 8, &fixtures, &scores));
+        let other = [Fixture { fixture_id: 9999, kickoff_time: 0 }, fixtures[1]];
+        assert_ne!(message, fixture_results_message(&pool, 7, &other, &scores));
+    }
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use crate::state::{BettingPool, LiquidityPool};
+use crate::constants::{
+    DEFAULT_PREDICTION_CUTOFF_ROUNDS, DEFAULT_CASH_OUT_MARGIN_BPS, DEFAULT_REPRICE_BAND_BPS, DEFAULT_SEED_FRACTION_BPS,
+    DEFAULT_CHALLENGE_PERIOD_SECS, DEFAULT_DISPUTE_BOND,
+};
+use crate::utils::scale_token_amount;
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -52,12 +57,25 @@ pub fn handler(
     ctx.accounts.betting_pool.token_mint = ctx.accounts.token_mint.key();
     ctx.accounts.betting_pool.protocol_treasury = ctx.accounts.protocol_treasury.key();
     ctx.accounts.betting_pool.liquidity_pool = liquidity_pool_key;
+    ctx.accounts.betting_pool.odds_oracle = Pubkey::default();
+    ctx.accounts.betting_pool.results_resolver = ctx.accounts.authority.key();
+    ctx.accounts.betting_pool.challenge_period = DEFAULT_CHALLENGE_PERIOD_SECS;
+    ctx.accounts.betting_pool.dispute_bond = scale_token_amount(DEFAULT_DISPUTE_BOND, ctx.accounts.token_mint.decimals);
     ctx.accounts.betting_pool.protocol_fee_bps = protocol_fee_bps;
     ctx.accounts.betting_pool.winner_share_bps = winner_share_bps;
     ctx.accounts.betting_pool.season_pool_share_bps = season_pool_share_bps;
+    ctx.accounts.betting_pool.cash_out_margin_bps = DEFAULT_CASH_OUT_MARGIN_BPS;
+    ctx.accounts.betting_pool.reprice_band_bps = DEFAULT_REPRICE_BAND_BPS;
+    ctx.accounts.betting_pool.token_decimals = ctx.accounts.token_mint.decimals;
+    ctx.accounts.betting_pool.seed_fraction_bps = DEFAULT_SEED_FRACTION_BPS;
     ctx.accounts.betting_pool.season_reward_pool = 0;
+    ctx.accounts.betting_pool.season_rewards_committed = 0;
+    ctx.accounts.betting_pool.cross_round_liability = 0;
+    ctx.accounts.betting_pool.outright_reserve = 0;
     ctx.accounts.betting_pool.next_bet_id = 1;
     ctx.accounts.betting_pool.next_round_id = 1;
+    ctx.accounts.betting_pool.season_start_round_id = 1;
+    ctx.accounts.betting_pool.prediction_cutoff_rounds = DEFAULT_PREDICTION_CUTOFF_ROUNDS;
     ctx.accounts.betting_pool.bump = betting_pool_bump;
 
     // Initialize liquidity pool
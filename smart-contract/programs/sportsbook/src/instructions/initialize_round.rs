@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::state::{BettingPool, RoundAccounting};
 use crate::errors::SportsbookError;
+use crate::constants::{PRICING_FIXED_ODDS, PRICING_PARIMUTUEL, ROUND_SIMULATED, MAX_MATCHES_PER_ROUND};
 
 #[derive(Accounts)]
-#[instruction(round_id: u64)]
+#[instruction(round_id: u64, pricing_mode: u8, num_matches: u8)]
 pub struct InitializeRound<'info> {
     #[account(mut)]
     pub betting_pool: Account<'info, BettingPool>,
@@ -11,7 +12,7 @@ pub struct InitializeRound<'info> {
     #[account(
         init,
         payer = authority,
-        space = RoundAccounting::LEN,
+        space = RoundAccounting::space(num_matches as usize),
         seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
         bump
     )]
@@ -23,13 +24,28 @@ pub struct InitializeRound<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeRound>, round_id: u64) -> Result<()> {
+pub fn handler(
+    ctx: Context<InitializeRound>,
+    round_id: u64,
+    pricing_mode: u8,
+    num_matches: u8,
+) -> Result<()> {
     // Validate round_id is sequential
     require!(
         round_id == ctx.accounts.betting_pool.next_round_id,
         SportsbookError::InvalidRoundId
     );
 
+    require!(
+        pricing_mode == PRICING_FIXED_ODDS || pricing_mode == PRICING_PARIMUTUEL,
+        SportsbookError::InvalidPricingMode
+    );
+
+    require!(
+        num_matches > 0 && num_matches as usize <= MAX_MATCHES_PER_ROUND,
+        SportsbookError::InvalidMatchCount
+    );
+
     // Increment next_round_id for future rounds
     ctx.accounts.betting_pool.next_round_id += 1;
 
@@ -38,9 +54,13 @@ pub fn handler(ctx: Context<InitializeRound>, round_id: u64) -> Result<()> {
     // Initialize round
     round_accounting.round_id = round_id;
     round_accounting.betting_pool = ctx.accounts.betting_pool.key();
-    round_accounting.match_pools = [Default::default(); 10];
-    round_accounting.locked_odds = [Default::default(); 10];
-    round_accounting.match_results = [Default::default(); 10];
+    round_accounting.num_matches = num_matches;
+    let n = num_matches as usize;
+    round_accounting.match_pools = vec![Default::default(); n];
+    round_accounting.locked_odds = vec![Default::default(); n];
+    round_accounting.book_overround_bps = vec![0; n];
+    round_accounting.match_results = vec![Default::default(); n];
+    round_accounting.match_scores = vec![Default::default(); n];
     round_accounting.total_bet_volume = 0;
     round_accounting.total_winning_pool = 0;
     round_accounting.total_losing_pool = 0;
@@ -58,9 +78,59 @@ pub fn handler(ctx: Context<InitializeRound>, round_id: u64) -> Result<()> {
     round_accounting.round_start_time = Clock::get()?.unix_timestamp;
     round_accounting.round_end_time = 0;
     round_accounting.settled = false;
+    round_accounting.pricing_mode = pricing_mode;
+    round_accounting.oracle_odds = false;
+    round_accounting.odds_sequence = 0;
+    round_accounting.round_kind = ROUND_SIMULATED;
+    round_accounting.fixtures = vec![Default::default(); n];
+    round_accounting.results_proposed_at = 0;
+    round_accounting.proposed_matches = 0;
+    round_accounting.match_settled_at = vec![0; n];
+    round_accounting.randomness_requested = 0;
+    round_accounting.betting_cutoffs = vec![0; n];
+    round_accounting.open_cross_round_bets = 0;
+    round_accounting.results_disputed = false;
     round_accounting.bump = ctx.bumps.round_accounting;
 
-    msg!("Round {} initialized", round_id);
+    msg!("Round {} initialized: {} matches (pricing mode {})", round_id, num_matches, pricing_mode);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SetBettingCutoffs<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Account<'info, RoundAccounting>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Set each match's betting cutoff (0 = none), so a round's matches close one by one
+///
+/// A closed match's cutoff can't be moved: bets already rely on it having closed
+pub fn set_betting_cutoffs_handler(
+    ctx: Context<SetBettingCutoffs>,
+    round_id: u64,
+    cutoffs: Vec<i64>,
+) -> Result<()> {
+    require!(cutoffs.len() == ctx.accounts.round_accounting.num_matches as usize, SportsbookError::ArrayLengthMismatch);
+
+    let now = Clock::get()?.unix_timestamp;
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    for (i, &cutoff) in cutoffs.iter().enumerate() {
+        round_accounting.set_betting_cutoff(i, cutoff, now)?;
+    }
+
+    msg!("Round {}: betting cutoffs {:?}", round_id, cutoffs);
 
     Ok(())
 }
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::{BettingPool, RoundAccounting, Bet, LegResult};
+use crate::errors::SportsbookError;
+use crate::utils::is_bet_lost;
+use super::cash_out::load_bet_markets;
+use super::place_bet::{load_leg_rounds, release_cross_round_bet};
+
+#[derive(Accounts)]
+#[instruction(bet_id: u64)]
+pub struct MarkBetDead<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), bet.round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [b"bet", betting_pool.key().as_ref(), bet_id.to_le_bytes().as_ref()],
+        bump = bet.bump,
+        constraint = !bet.claimed @ SportsbookError::BetAlreadyClaimed,
+    )]
+    pub bet: Box<Account<'info, Bet>>,
+
+    /// Anyone can mark a lost bet dead
+    pub caller: Signer<'info>,
+
+    // Remaining accounts: one writable Market per prediction, in prediction order,
+    // then the writable RoundAccounting of every other round the legs reference, in leg order
+}
+
+/// Settle a bet that has already lost before its other legs resolve
+///
+/// The stakes of its unresolved legs are pulled out of their fixed-odds markets, freeing
+/// the liability they reserved (parimutuel pools keep them: winners share those pools).
+/// Keepers must call this for lost cross-round bets: until they close, every round they
+/// touch is blocked from finalizing revenue
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MarkBetDead<'info>>,
+    bet_id: u64,
+) -> Result<()> {
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let num_predictions = ctx.accounts.bet.num_predictions as usize;
+    require!(
+        ctx.remaining_accounts.len() >= num_predictions,
+        SportsbookError::MarketMismatch
+    );
+    let (market_infos, round_infos) = ctx.remaining_accounts.split_at(num_predictions);
+    let mut markets = load_bet_markets(market_infos, &ctx.accounts.bet, betting_pool_key)?;
+    let mut other_rounds = load_leg_rounds(round_infos, &ctx.accounts.bet.other_rounds(), betting_pool_key)?;
+    let predictions = ctx.accounts.bet.get_predictions().to_vec();
+
+    let mut lost_legs = 0u16;
+    for (i, (prediction, market)) in predictions.iter().zip(markets.iter()).enumerate() {
+        if !market.resolved {
+            continue;
+        }
+        let selection = market
+            .selection(prediction.predicted_outcome)
+            .ok_or(SportsbookError::InvalidOutcome)?;
+        if selection.result == LegResult::Lost {
+            lost_legs |= 1 << i;
+        }
+    }
+    require!(
+        is_bet_lost(predictions.len() as u8, ctx.accounts.bet.system_sizes, lost_legs),
+        SportsbookError::BetStillLive
+    );
+
+    // Release the liability still reserved in markets that haven't resolved yet
+    let mut freed = 0u64;
+    for (prediction, market) in predictions.iter().zip(markets.iter_mut()) {
+        if market.resolved || market.parimutuel {
+            continue;
+        }
+        market.remove_stake(prediction.predicted_outcome, prediction.amount_in_pool, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+        freed += prediction.amount_in_pool;
+    }
+
+    // Pulled stakes never reach a market's losing pool on resolve, so count them here
+    // (against the round the bet was placed in, which holds its stake)
+    ctx.accounts.round_accounting.total_losing_pool += freed;
+
+    ctx.accounts.bet.settled = true;
+    ctx.accounts.bet.claimed = true;
+
+    release_cross_round_bet(
+        &mut ctx.accounts.betting_pool,
+        &mut ctx.accounts.round_accounting,
+        &mut other_rounds,
+        &mut ctx.accounts.bet,
+    )?;
+
+    msg!("Bet {} lost: marked dead, {} of stakes released from open markets", bet_id, freed);
+
+    Ok(())
+}
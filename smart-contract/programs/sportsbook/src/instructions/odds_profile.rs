@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::{BettingPool, OddsProfile, OddsCurve};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct InitializeOddsProfile<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = OddsProfile::LEN,
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump
+    )]
+    pub odds_profile: Account<'info, OddsProfile>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the pool's odds profile; rounds seeded and markets created afterwards are priced by it
+pub fn handler(
+    ctx: Context<InitializeOddsProfile>,
+    curve: OddsCurve,
+    overround_bps: [u16; NUM_MARKET_TYPES],
+) -> Result<()> {
+    validate_profile(&curve, &overround_bps)?;
+
+    let odds_profile = &mut ctx.accounts.odds_profile;
+    odds_profile.betting_pool = ctx.accounts.betting_pool.key();
+    odds_profile.curve = curve;
+    odds_profile.overround_bps = overround_bps;
+    odds_profile.bump = ctx.bumps.odds_profile;
+
+    msg!("Odds profile initialized: curve {}, overround {:?}bps", curve.curve, overround_bps);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateOddsProfile<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump = odds_profile.bump,
+    )]
+    pub odds_profile: Account<'info, OddsProfile>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Retune the odds profile (rounds already seeded and markets already created keep their odds)
+pub fn update_odds_profile_handler(
+    ctx: Context<UpdateOddsProfile>,
+    curve: OddsCurve,
+    overround_bps: [u16; NUM_MARKET_TYPES],
+) -> Result<()> {
+    validate_profile(&curve, &overround_bps)?;
+
+    let odds_profile = &mut ctx.accounts.odds_profile;
+    odds_profile.curve = curve;
+    odds_profile.overround_bps = overround_bps;
+
+    msg!("Odds profile updated: curve {}, overround {:?}bps", curve.curve, overround_bps);
+
+    Ok(())
+}
+
+fn validate_profile(curve: &OddsCurve, overround_bps: &[u16; NUM_MARKET_TYPES]) -> Result<()> {
+    require!(curve.is_valid(), SportsbookError::InvalidOddsProfile);
+    require!(
+        overround_bps.iter().all(|&o| o <= MAX_OVERROUND_BPS),
+        SportsbookError::InvalidOddsProfile
+    );
+    Ok(())
+}
@@ -1,12 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{BettingPool, RoundAccounting, Bet, Prediction};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{BettingPool, RoundAccounting, Bet, Prediction, Market};
 use crate::errors::SportsbookError;
 use crate::constants::*;
-use crate::utils::{calculate_parlay_multiplier_dynamic, calculate_odds_weighted_allocations, calculate_max_payout};
+use crate::utils::{
+    calculate_parlay_multiplier_dynamic, calculate_odds_weighted_allocations, calculate_max_payout,
+    calculate_market_imbalance, calculate_system_multipliers, calculate_system_allocations,
+    is_valid_system, scale_token_amount,
+};
 
 #[derive(Accounts)]
-#[instruction(round_id: u64)]
+#[instruction(round_id: u64, outcomes: Vec<u8>)]
 pub struct PlaceBet<'info> {
     #[account(mut)]
     pub betting_pool: Box<Account<'info, BettingPool>>,
@@ -23,7 +27,7 @@ pub struct PlaceBet<'info> {
     #[account(
         init,
         payer = bettor,
-        space = Bet::LEN,
+        space = Bet::space(outcomes.len()),
         seeds = [
             b"bet",
             betting_pool.key().as_ref(),
@@ -49,41 +53,107 @@ pub struct PlaceBet<'info> {
     /// If provided and has balance, user gets benefits
     pub team_token_account: Option<Box<Account<'info, TokenAccount>>>,
 
+    /// Optional: Mint of the team token account (its decimals scale the minimum balance)
+    pub team_token_mint: Option<Box<Account<'info, Mint>>>,
+
     #[account(mut)]
     pub bettor: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    // Remaining accounts: one writable Market per leg, in the same order as `outcomes`,
+    // then the writable RoundAccounting of every other round the legs reference, in leg order
 }
 
-pub fn handler(
-    ctx: Context<PlaceBet>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PlaceBet<'info>>,
     round_id: u64,
-    match_indices: Vec<u8>,
     outcomes: Vec<u8>,
+    system_sizes: u16,
     amount: u64,
 ) -> Result<()> {
     // Validate inputs
     require!(amount > 0, SportsbookError::InvalidAmount);
-    require!(amount <= MAX_BET_AMOUNT, SportsbookError::BetExceedsMaximum);
+    let token_decimals = ctx.accounts.betting_pool.token_decimals;
+    require!(
+        amount <= scale_token_amount(MAX_BET_AMOUNT, token_decimals),
+        SportsbookError::BetExceedsMaximum
+    );
     require!(
-        match_indices.len() == outcomes.len(),
+        ctx.remaining_accounts.len() >= outcomes.len(),
         SportsbookError::ArrayLengthMismatch
     );
     require!(
-        match_indices.len() > 0 && match_indices.len() <= MATCHES_PER_ROUND,
+        !outcomes.is_empty() && outcomes.len() <= MAX_LEGS_PER_BET,
         SportsbookError::InvalidBetCount
     );
+    require!(
+        is_valid_system(outcomes.len() as u8, system_sizes),
+        SportsbookError::InvalidSystemBet
+    );
+
+    // Load and validate each leg's market; legs on other rounds make a cross-round accumulator
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let now = Clock::get()?.unix_timestamp;
+    let (market_infos, round_infos) = ctx.remaining_accounts.split_at(outcomes.len());
+    let mut markets: Vec<Account<Market>> = Vec::with_capacity(outcomes.len());
+    for (market_info, &outcome) in market_infos.iter().zip(outcomes.iter()) {
+        require!(market_info.is_writable, SportsbookError::MarketMismatch);
+        let market: Account<Market> = Account::try_from(market_info)?;
+
+        require!(market.betting_pool == betting_pool_key, SportsbookError::MarketMismatch);
+        require!(market.odds(outcome) > 0, SportsbookError::InvalidOutcome);
+
+        // One leg per match: same-match legs (e.g. home win + over) are correlated
+        require!(
+            !markets
+                .iter()
+                .any(|m| m.round_id == market.round_id && m.match_index == market.match_index),
+            SportsbookError::DuplicateMatchInBet
+        );
+
+        markets.push(market);
+    }
+    require!(
+        markets.iter().any(|m| m.round_id == round_id),
+        SportsbookError::InvalidCrossRoundBet
+    );
 
-    // Validate match indices and outcomes
-    for i in 0..match_indices.len() {
+    // The other rounds' accounting follows the markets, one per round in leg order
+    let mut other_round_ids: Vec<u64> = Vec::new();
+    for market in markets.iter() {
+        if market.round_id != round_id && !other_round_ids.contains(&market.round_id) {
+            other_round_ids.push(market.round_id);
+        }
+    }
+    let mut other_rounds = load_leg_rounds(round_infos, &other_round_ids, betting_pool_key)?;
+    let pricing_mode = ctx.accounts.round_accounting.pricing_mode;
+    for other_round in other_rounds.iter() {
         require!(
-            (match_indices[i] as usize) < MATCHES_PER_ROUND,
-            SportsbookError::InvalidMatchIndex
+            other_round.seeded && !other_round.settled && other_round.pricing_mode == PRICING_FIXED_ODDS,
+            SportsbookError::InvalidCrossRoundBet
         );
+    }
+    let cross_round = !other_rounds.is_empty();
+    require!(
+        !cross_round || pricing_mode == PRICING_FIXED_ODDS,
+        SportsbookError::InvalidCrossRoundBet
+    );
+
+    // Every leg's match must still be open in its own round
+    for market in markets.iter() {
+        let leg_round: &RoundAccounting = if market.round_id == round_id {
+            &ctx.accounts.round_accounting
+        } else {
+            other_rounds
+                .iter()
+                .find(|r| r.round_id == market.round_id)
+                .ok_or(SportsbookError::InvalidCrossRoundBet)?
+        };
         require!(
-            outcomes[i] >= 1 && outcomes[i] <= 3,
-            SportsbookError::InvalidOutcome
+            leg_round.match_open(market.match_index as usize, now),
+            SportsbookError::BettingClosed
         );
     }
 
@@ -93,11 +163,7 @@ pub fn handler(
     let betting_pool_fee_bps = ctx.accounts.betting_pool.protocol_fee_bps;
 
     // Check if user holds team tokens for benefits
-    let has_team_tokens = if let Some(ref team_token_account) = ctx.accounts.team_token_account {
-        team_token_account.amount >= MIN_TEAM_TOKEN_BALANCE
-    } else {
-        false
-    };
+    let has_team_tokens = holds_team_tokens(&ctx.accounts.team_token_account, &ctx.accounts.team_token_mint);
 
     // Transfer user's stake
     let cpi_accounts = Transfer {
@@ -145,58 +211,27 @@ pub fn handler(
     ctx.accounts.round_accounting.total_user_deposits += amount_after_fee;
 
     // Determine if this is a parlay
-    let is_parlay = match_indices.len() > 1;
-
-    // Calculate dynamic parlay multiplier
-    let mut parlay_multiplier = calculate_parlay_multiplier_dynamic(
-        &ctx.accounts.round_accounting,
-        &match_indices,
-        match_indices.len() as u8,
-    );
-
-    // Apply odds boost for team token holders (5% better multiplier)
-    if has_team_tokens {
-        let boost = (parlay_multiplier as u128)
-            .checked_mul(TEAM_TOKEN_ODDS_BOOST_BPS as u128)
-            .ok_or(SportsbookError::CalculationOverflow)?
-            .checked_div(BPS_DENOMINATOR as u128)
-            .ok_or(SportsbookError::CalculationOverflow)? as u64;
-
-        parlay_multiplier = parlay_multiplier.saturating_add(boost);
-
-        msg!("Team token holder: odds boost applied (+{})", boost);
+    let is_parlay = markets.len() > 1;
+
+    let parimutuel = pricing_mode == PRICING_PARIMUTUEL;
+    let pricing = price_legs(&markets, &outcomes, system_sizes, amount_after_fee, has_team_tokens, parimutuel)?;
+    let parlay_multiplier = pricing.multiplier;
+    let allocations = pricing.allocations;
+    let total_allocated = pricing.total_allocated;
+
+    if cross_round {
+        ctx.accounts.round_accounting.open_cross_round_bets += 1;
+        for other_round in other_rounds.iter_mut() {
+            other_round.open_cross_round_bets += 1;
+            other_round.exit(&crate::ID)?;
+        }
     }
 
-    // CRITICAL: Check protocol has enough capital to cover potential payout
-    // This prevents insolvency if multiple large parlays win
-    let max_possible_payout = calculate_max_payout(
-        amount_after_fee,
-        match_indices.len() as u8,
-        parlay_multiplier,
-    );
-
-    let current_balance = ctx.accounts.betting_pool_token_account.amount;
-    require!(
-        current_balance >= max_possible_payout,
-        SportsbookError::InsufficientProtocolLiquidity
-    );
-
     // Increment parlay count (FOMO mechanism)
     if is_parlay {
         ctx.accounts.round_accounting.parlay_count += 1;
     }
 
-    // Calculate odds-weighted allocations
-    // Note: Protocol provides all liquidity, no borrowing needed
-    let (allocations, total_allocated, _lp_borrowed) = calculate_odds_weighted_allocations(
-        &ctx.accounts.round_accounting,
-        &match_indices,
-        &outcomes,
-        amount_after_fee,
-        parlay_multiplier,
-    )
-    .map_err(|_| SportsbookError::CalculationOverflow)?;
-
     // Get bet ID and increment
     let bet_id = ctx.accounts.betting_pool.next_bet_id;
     ctx.accounts.betting_pool.next_bet_id += 1;
@@ -210,42 +245,321 @@ pub fn handler(
     ctx.accounts.bet.allocated_amount = total_allocated;
     ctx.accounts.bet.bonus = 0; // No bonus
     ctx.accounts.bet.locked_multiplier = parlay_multiplier;
-    ctx.accounts.bet.num_predictions = match_indices.len() as u8;
+    ctx.accounts.bet.num_predictions = markets.len() as u8;
+    ctx.accounts.bet.system_sizes = system_sizes;
+    ctx.accounts.bet.system_multipliers = pricing.system_multipliers;
     ctx.accounts.bet.settled = false;
     ctx.accounts.bet.claimed = false;
     ctx.accounts.bet.claim_deadline = 0; // Will be set when round is settled
     ctx.accounts.bet.bounty_claimer = None;
+    ctx.accounts.bet.reserved_liability = 0;
     ctx.accounts.bet.bump = ctx.bumps.bet;
 
     // Add predictions and update pools
-    let mut predictions = [Prediction {
-        match_index: 0,
-        predicted_outcome: 0,
-        amount_in_pool: 0,
-    }; 10];
-
-    for i in 0..match_indices.len() {
-        let match_index = match_indices[i];
+    let mut predictions = Vec::with_capacity(markets.len());
+
+    for (i, market) in markets.iter_mut().enumerate() {
         let outcome = outcomes[i];
         let allocation = allocations[i];
 
-        predictions[i] = Prediction {
-            match_index,
+        let prediction = Prediction {
+            round_id: market.round_id,
+            match_index: market.match_index,
+            market_type: market.market_type,
             predicted_outcome: outcome,
             amount_in_pool: allocation,
+            locked_odds: market.odds(outcome),
         };
 
-        // Add to appropriate match pool (with overflow protection)
-        let pool = &mut ctx.accounts.round_accounting.match_pools[match_index as usize];
-        pool.add_to_pool(outcome, allocation)?;
+        // Add to the selection's pool at the price it took (enforces the market's
+        // liability cap), then let the market reprice for the next bettor
+        market.add_stake(outcome, allocation, prediction.locked_odds)?;
+        market.exit(&crate::ID)?;
+        predictions.push(prediction);
     }
 
     ctx.accounts.bet.predictions = predictions;
 
+    // CRITICAL: Check protocol has enough capital to cover potential payout
+    // This prevents insolvency if multiple large parlays win
+    // (parimutuel payouts come out of the pools themselves)
+    let mut reserved_liability = 0u64;
+    if !parimutuel {
+        // Every combination winning at the legs' locked odds
+        let max_possible_payout = calculate_max_payout(
+            &ctx.accounts.bet,
+            scale_token_amount(MAX_PAYOUT_PER_BET, token_decimals),
+        )
+        .map_err(|_| SportsbookError::CalculationOverflow)?;
+
+        // Payouts already reserved for open cross-round bets and outright markets aren't available
+        let current_balance = ctx.accounts.betting_pool_token_account.amount;
+        require!(
+            current_balance
+                >= max_possible_payout
+                    .saturating_add(ctx.accounts.betting_pool.cross_round_liability)
+                    .saturating_add(ctx.accounts.betting_pool.outright_reserve),
+            SportsbookError::InsufficientProtocolLiquidity
+        );
+
+        // A cross-round bet stays open across several rounds' revenue finalization,
+        // so its max payout is held back until it closes
+        if cross_round {
+            reserved_liability = max_possible_payout;
+            ctx.accounts.betting_pool.cross_round_liability += reserved_liability;
+            ctx.accounts.bet.reserved_liability = reserved_liability;
+        }
+    }
+
     msg!("Bet {} placed successfully", bet_id);
+    if cross_round {
+        msg!("Cross-round bet: legs in rounds {} and {:?}, {} reserved", round_id, other_round_ids, reserved_liability);
+    }
     msg!("Amount: {}, After fee: {}", amount, amount_after_fee);
     msg!("Parlay multiplier: {}", parlay_multiplier);
     msg!("Allocated: {}", total_allocated);
 
     Ok(())
 }
+
+/// Load the accounting of each round in `round_ids` (remaining accounts, same order, writable)
+pub fn load_leg_rounds<'info>(
+    round_infos: &'info [AccountInfo<'info>],
+    round_ids: &[u64],
+    betting_pool_key: Pubkey,
+) -> Result<Vec<Account<'info, RoundAccounting>>> {
+    require!(
+        round_infos.len() == round_ids.len(),
+        SportsbookError::InvalidCrossRoundBet
+    );
+
+    let mut rounds = Vec::with_capacity(round_ids.len());
+    for (round_info, &round_id) in round_infos.iter().zip(round_ids.iter()) {
+        require!(round_info.is_writable, SportsbookError::InvalidCrossRoundBet);
+        let round: Account<RoundAccounting> = Account::try_from(round_info)?;
+        require!(
+            round.betting_pool == betting_pool_key && round.round_id == round_id,
+            SportsbookError::InvalidCrossRoundBet
+        );
+        rounds.push(round);
+    }
+
+    Ok(rounds)
+}
+
+/// Close out a cross-round bet: release its reserved payout and its hold on every round it touches
+///
+/// Only claim_winnings (won bets) and mark_bet_dead (lost bets) close a cross-round bet, and
+/// every round it touches can't finalize revenue until then. Claims open to anyone (with a
+/// bounty) after the claim deadline, but nobody claims a lost bet: a keeper must mark lost
+/// cross-round bets dead once their losing leg resolves.
+pub fn release_cross_round_bet(
+    betting_pool: &mut BettingPool,
+    round_accounting: &mut RoundAccounting,
+    other_rounds: &mut [Account<RoundAccounting>],
+    bet: &mut Bet,
+) -> Result<()> {
+    if other_rounds.is_empty() {
+        return Ok(());
+    }
+
+    release_cross_round_holds(
+        &mut betting_pool.cross_round_liability,
+        round_accounting,
+        other_rounds.iter_mut().map(|other_round| &mut **other_round),
+        bet,
+    );
+    for other_round in other_rounds.iter() {
+        other_round.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Drop a cross-round bet's reserved payout and its open-bet count on each round
+fn release_cross_round_holds<'a>(
+    cross_round_liability: &mut u64,
+    round_accounting: &mut RoundAccounting,
+    other_rounds: impl Iterator<Item = &'a mut RoundAccounting>,
+    bet: &mut Bet,
+) {
+    *cross_round_liability = cross_round_liability.saturating_sub(bet.reserved_liability);
+    bet.reserved_liability = 0;
+
+    round_accounting.open_cross_round_bets = round_accounting.open_cross_round_bets.saturating_sub(1);
+    for other_round in other_rounds {
+        other_round.open_cross_round_bets = other_round.open_cross_round_bets.saturating_sub(1);
+    }
+}
+
+/// Does the bettor hold enough team tokens for the fee discount and odds boost?
+///
+/// The minimum balance is scaled to the team token mint's decimals
+pub fn holds_team_tokens(
+    team_token_account: &Option<Box<Account<TokenAccount>>>,
+    team_token_mint: &Option<Box<Account<Mint>>>,
+) -> bool {
+    match (team_token_account, team_token_mint) {
+        (Some(account), Some(mint)) => {
+            account.mint == mint.key()
+                && account.amount >= scale_token_amount(MIN_TEAM_TOKEN_BALANCE, mint.decimals)
+        }
+        _ => false,
+    }
+}
+
+/// Locked multipliers and allocations for a bet's legs
+pub struct LegPricing {
+    /// Headline parlay multiplier (largest system multiplier for system bets)
+    pub multiplier: u64,
+
+    /// Multiplier per system combination size, one per leg (all zero for accumulators)
+    pub system_multipliers: Vec<u64>,
+
+    /// Allocation per leg, in leg order
+    pub allocations: Vec<u64>,
+
+    /// Sum of the allocations
+    pub total_allocated: u64,
+}
+
+/// Price a set of legs: lock the parlay multiplier(s) from the markets' imbalances
+/// and split the stake into odds-weighted allocations
+///
+/// Parimutuel rounds pay no parlay bonus or odds boost: winners only ever share the pools
+pub fn price_legs(
+    markets: &[Account<Market>],
+    outcomes: &[u8],
+    system_sizes: u16,
+    amount_after_fee: u64,
+    has_team_tokens: bool,
+    parimutuel: bool,
+) -> Result<LegPricing> {
+    // Calculate dynamic parlay multiplier from the legs' market imbalances
+    // (system bets lock one multiplier per combination size, the largest is the headline)
+    let leg_imbalances: Vec<u64> = markets.iter().map(|m| calculate_market_imbalance(m)).collect();
+    let mut system_multipliers = vec![0u64; markets.len()];
+    let mut parlay_multiplier = if parimutuel {
+        if system_sizes != 0 {
+            system_multipliers = vec![ODDS_SCALE; markets.len()];
+        }
+        ODDS_SCALE
+    } else if system_sizes == 0 {
+        calculate_parlay_multiplier_dynamic(&leg_imbalances)
+    } else {
+        system_multipliers = calculate_system_multipliers(&leg_imbalances, system_sizes);
+        system_multipliers.iter().copied().max().unwrap_or(ODDS_SCALE)
+    };
+
+    // Apply odds boost for team token holders (5% better multiplier)
+    if has_team_tokens && !parimutuel {
+        let boost = team_token_boost(parlay_multiplier)?;
+        parlay_multiplier = parlay_multiplier.saturating_add(boost);
+
+        for multiplier in system_multipliers.iter_mut() {
+            *multiplier = multiplier.saturating_add(team_token_boost(*multiplier)?);
+        }
+
+        msg!("Team token holder: odds boost applied (+{})", boost);
+    }
+
+    // Calculate odds-weighted allocations
+    // Note: Protocol provides all liquidity, no borrowing needed
+    let leg_odds: Vec<u64> = markets
+        .iter()
+        .zip(outcomes.iter())
+        .map(|(market, &outcome)| market.odds(outcome))
+        .collect();
+    let (allocations, total_allocated, _lp_borrowed) = if system_sizes == 0 {
+        calculate_odds_weighted_allocations(&leg_odds, amount_after_fee, parlay_multiplier)
+    } else {
+        calculate_system_allocations(&leg_odds, system_sizes, amount_after_fee, &system_multipliers)
+    }
+    .map_err(|_| SportsbookError::CalculationOverflow)?;
+
+    Ok(LegPricing {
+        multiplier: parlay_multiplier,
+        system_multipliers,
+        allocations,
+        total_allocated,
+    })
+}
+
+/// Extra multiplier for team token holders (5% of the multiplier)
+fn team_token_boost(multiplier: u64) -> Result<u64> {
+    Ok((multiplier as u128)
+        .checked_mul(TEAM_TOKEN_ODDS_BOOST_BPS as u128)
+        .ok_or(SportsbookError::CalculationOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(SportsbookError::CalculationOverflow)? as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::round_accounting::tests::make_round;
+
+    fn cross_round_bet(reserved_liability: u64) -> Bet {
+        let leg = |round_id| Prediction {
+            round_id,
+            match_index: 0,
+            market_type: MARKET_MATCH_RESULT,
+            predicted_outcome: 1,
+            amount_in_pool: 100,
+            locked_odds: 2_000_000_000,
+        };
+        Bet {
+            bettor: Pubkey::default(),
+            round_id: 1,
+            bet_id: 0,
+            amount: 200,
+            amount_after_fee: 200,
+            allocated_amount: 200,
+            bonus: 0,
+            locked_multiplier: ODDS_SCALE,
+            num_predictions: 2,
+            predictions: vec![leg(1), leg(2)],
+            system_sizes: 0,
+            system_multipliers: vec![0; 2],
+            settled: false,
+            claimed: false,
+            claim_deadline: 0,
+            bounty_claimer: None,
+            reserved_liability,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_closing_cross_round_bets_unblocks_both_rounds() {
+        // Two open cross-round bets spanning rounds 1 and 2: one won (claim_winnings),
+        // one lost (mark_bet_dead); both close through the same release
+        let mut round = make_round(10);
+        let mut other_round = make_round(10);
+        round.open_cross_round_bets = 2;
+        other_round.open_cross_round_bets = 2;
+        let mut won_bet = cross_round_bet(400);
+        let mut lost_bet = cross_round_bet(600);
+        let mut cross_round_liability = 1_000;
+
+        release_cross_round_holds(
+            &mut cross_round_liability,
+            &mut round,
+            std::iter::once(&mut other_round),
+            &mut won_bet,
+        );
+        assert_eq!(cross_round_liability, 600);
+        assert_eq!(won_bet.reserved_liability, 0);
+        assert_eq!((round.open_cross_round_bets, other_round.open_cross_round_bets), (1, 1));
+
+        release_cross_round_holds(
+            &mut cross_round_liability,
+            &mut round,
+            std::iter::once(&mut other_round),
+            &mut lost_bet,
+        );
+        assert_eq!(cross_round_liability, 0);
+        // Neither round blocks revenue finalization any more
+        assert_eq!((round.open_cross_round_bets, other_round.open_cross_round_bets), (0, 0));
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{BettingPool, RoundAccounting, Market, LegResult};
+use crate::errors::SportsbookError;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct ResolveMarket<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"market",
+            betting_pool.key().as_ref(),
+            round_id.to_le_bytes().as_ref(),
+            market.match_index.to_le_bytes().as_ref(),
+            market.market_type.to_le_bytes().as_ref()
+        ],
+        bump = market.bump,
+        constraint = !market.resolved @ SportsbookError::MarketAlreadyResolved,
+        constraint = round_accounting.match_settled(market.match_index as usize) @ SportsbookError::MatchNotSettled,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    /// Anyone can resolve a market once its match is settled
+    pub resolver: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveMarket>, round_id: u64) -> Result<()> {
+    let match_index = ctx.accounts.market.match_index as usize;
+    let score = ctx.accounts.round_accounting.match_scores[match_index];
+
+    let market = &mut ctx.accounts.market;
+    market.resolve(&score);
+
+    // Fold the market into the round's settlement totals
+    let mut winning_pool = 0u64;
+    let mut losing_pool = 0u64;
+    for selection in market.selections[..market.num_selections as usize].iter() {
+        match selection.result {
+            LegResult::Won | LegResult::HalfWin => winning_pool += selection.pool,
+            LegResult::Lost | LegResult::HalfLose => losing_pool += selection.pool,
+            LegResult::Void | LegResult::Pending => {}
+        }
+    }
+    let owed = market.total_owed()?;
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    round_accounting.total_winning_pool += winning_pool;
+    round_accounting.total_losing_pool += losing_pool;
+    round_accounting.total_reserved_for_winners += owed;
+
+    msg!(
+        "Market resolved: round {}, match {}, type {}",
+        round_id,
+        match_index,
+        market.market_type
+    );
+    msg!("Winning pool: {}, losing pool: {}, owed: {}", winning_pool, losing_pool, owed);
+
+    Ok(())
+}
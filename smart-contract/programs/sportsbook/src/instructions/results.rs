@@ -0,0 +1,385 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{BettingPool, RoundAccounting, ResultsDispute, MatchScore};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct ProposeResults<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = round_accounting.seeded @ SportsbookError::RoundNotSeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = round_accounting.round_kind == ROUND_SIMULATED @ SportsbookError::InvalidRoundKind,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority)]
+    pub authority: Signer<'info>,
+}
+
+/// Propose scorelines for some of a simulated round's matches
+///
+/// Matches settle individually: nothing is final yet, the results stay open to a bonded
+/// dispute for the challenge period, and only finalize_results settles the proposed
+/// matches (the round itself settles with its last match)
+pub fn handler(
+    ctx: Context<ProposeResults>,
+    round_id: u64,
+    match_indices: Vec<u8>,
+    match_scores: Vec<MatchScore>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.round_accounting.results_proposed_at == 0,
+        SportsbookError::ResultsAlreadyProposed
+    );
+    let proposed_matches = proposal_mask(&ctx.accounts.round_accounting, &match_indices, &match_scores)?;
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    write_results(round_accounting, &match_indices, &match_scores);
+    round_accounting.proposed_matches = proposed_matches;
+    round_accounting.results_proposed_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Round {}: results proposed for matches {:?}, disputable for {}s",
+        round_id,
+        match_indices,
+        ctx.accounts.betting_pool.challenge_period
+    );
+
+    Ok(())
+}
+
+/// Check a proposal covers distinct, unsettled matches, returning them as a bitmask
+pub fn proposal_mask(
+    round_accounting: &RoundAccounting,
+    match_indices: &[u8],
+    match_scores: &[MatchScore],
+) -> Result<u32> {
+    require!(
+        !match_indices.is_empty() && match_indices.len() == match_scores.len(),
+        SportsbookError::ArrayLengthMismatch
+    );
+
+    let mut mask = 0u32;
+    for &match_index in match_indices {
+        require!(
+            match_index < round_accounting.num_matches && mask & (1 << match_index) == 0,
+            SportsbookError::InvalidMatchIndex
+        );
+        require!(
+            !round_accounting.match_settled(match_index as usize),
+            SportsbookError::MatchAlreadySettled
+        );
+        mask |= 1 << match_index;
+    }
+
+    Ok(mask)
+}
+
+/// Store scorelines and the 1X2 results they imply
+pub fn write_results(round_accounting: &mut RoundAccounting, match_indices: &[u8], match_scores: &[MatchScore]) {
+    for (&match_index, score) in match_indices.iter().zip(match_scores.iter()) {
+        round_accounting.match_scores[match_index as usize] = *score;
+        round_accounting.match_results[match_index as usize] = score.outcome();
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct DisputeResults<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = !round_accounting.results_disputed @ SportsbookError::ResultsDisputed,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        init,
+        payer = disputer,
+        space = ResultsDispute::LEN,
+        seeds = [b"dispute", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub dispute: Box<Account<'info, ResultsDispute>>,
+
+    /// Disputer's token account (posts the bond)
+    #[account(mut)]
+    pub disputer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Betting pool's token account (holds the bond until the resolver rules)
+    #[account(
+        mut,
+        constraint = betting_pool_token_account.owner == betting_pool.key() @ SportsbookError::InvalidTokenAccount,
+        constraint = betting_pool_token_account.mint == betting_pool.token_mint @ SportsbookError::InvalidTokenAccount,
+    )]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Challenge a round's proposed results, escalating them to the results resolver
+pub fn dispute_results_handler(ctx: Context<DisputeResults>, round_id: u64) -> Result<()> {
+    let proposed_at = ctx.accounts.round_accounting.results_proposed_at;
+    require!(proposed_at != 0, SportsbookError::ResultsNotProposed);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < proposed_at + ctx.accounts.betting_pool.challenge_period,
+        SportsbookError::ChallengePeriodClosed
+    );
+
+    let bond = ctx.accounts.betting_pool.dispute_bond;
+    if bond > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.disputer_token_account.to_account_info(),
+            to: ctx.accounts.betting_pool_token_account.to_account_info(),
+            authority: ctx.accounts.disputer.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), bond)?;
+    }
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.round_id = round_id;
+    dispute.disputer = ctx.accounts.disputer.key();
+    dispute.disputer_token_account = ctx.accounts.disputer_token_account.key();
+    dispute.bond = bond;
+    dispute.raised_at = now;
+    dispute.bump = ctx.bumps.dispute;
+
+    ctx.accounts.round_accounting.results_disputed = true;
+
+    msg!("Round {} results disputed by {} (bond {})", round_id, dispute.disputer, bond);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct ResolveDispute<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = dispute.bump,
+        has_one = disputer,
+        has_one = disputer_token_account,
+        close = disputer,
+    )]
+    pub dispute: Box<Account<'info, ResultsDispute>>,
+
+    /// Disputer (receives the dispute account's rent)
+    /// CHECK: must match the disputer recorded on the dispute
+    #[account(mut)]
+    pub disputer: UncheckedAccount<'info>,
+
+    /// Disputer's token account (bond refund if the dispute is upheld)
+    #[account(mut)]
+    pub disputer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Betting pool's token account (holds the bond)
+    #[account(
+        mut,
+        constraint = betting_pool_token_account.owner == betting_pool.key() @ SportsbookError::InvalidTokenAccount,
+        constraint = betting_pool_token_account.mint == betting_pool.token_mint @ SportsbookError::InvalidTokenAccount,
+    )]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(constraint = resolver.key() == betting_pool.results_resolver @ SportsbookError::NotResultsResolver)]
+    pub resolver: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Rule on a disputed proposal
+///
+/// With `corrected_scores` (one per proposed match, in match order) the dispute is upheld:
+/// the corrected results settle those matches at once and the bond is returned. Without,
+/// the proposal stands, the bond is forfeited to the pool, and it can be finalized once
+/// the challenge period has passed.
+pub fn resolve_dispute_handler(
+    ctx: Context<ResolveDispute>,
+    round_id: u64,
+    corrected_scores: Option<Vec<MatchScore>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let bond = ctx.accounts.dispute.bond;
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    round_accounting.results_disputed = false;
+
+    match corrected_scores {
+        Some(match_scores) => {
+            let match_indices: Vec<u8> = (0..round_accounting.num_matches)
+                .filter(|&i| round_accounting.proposed_matches & (1 << i) != 0)
+                .collect();
+            require!(
+                match_scores.len() == match_indices.len(),
+                SportsbookError::ArrayLengthMismatch
+            );
+
+            // The resolver's ruling is final, so the corrected results settle immediately
+            write_results(round_accounting, &match_indices, &match_scores);
+            round_accounting.settle_proposed_matches(now);
+
+            if bond > 0 {
+                let seeds = &[b"betting_pool".as_ref(), &[ctx.accounts.betting_pool.bump]];
+                let signer = &[&seeds[..]];
+
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.betting_pool_token_account.to_account_info(),
+                    to: ctx.accounts.disputer_token_account.to_account_info(),
+                    authority: ctx.accounts.betting_pool.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), bond)?;
+            }
+
+            msg!("Round {} dispute upheld: results corrected and settled, bond returned", round_id);
+        }
+        None => {
+            msg!("Round {} dispute rejected: proposed results stand, bond forfeited", round_id);
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct FinalizeResults<'info> {
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = !round_accounting.results_disputed @ SportsbookError::ResultsDisputed,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    /// Anyone can finalize once the challenge period has passed
+    pub finalizer: Signer<'info>,
+}
+
+/// Settle the proposed matches once the challenge period has passed
+pub fn finalize_results_handler(ctx: Context<FinalizeResults>, round_id: u64) -> Result<()> {
+    let proposed_at = ctx.accounts.round_accounting.results_proposed_at;
+    require!(proposed_at != 0, SportsbookError::ResultsNotProposed);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= proposed_at + ctx.accounts.betting_pool.challenge_period,
+        SportsbookError::ChallengePeriodOpen
+    );
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    let settled_matches = round_accounting.proposed_matches.count_ones();
+    round_accounting.settle_proposed_matches(now);
+
+    // Each market of a settled match is then resolved against its score (resolve_market)
+    msg!("Round {}: {} matches settled", round_id, settled_matches);
+    if round_accounting.settled {
+        msg!("Round {} settled", round_id);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureResultsDisputes<'info> {
+    #[account(mut)]
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Set the challenge period, the dispute bond and who rules on disputes
+pub fn configure_results_disputes_handler(
+    ctx: Context<ConfigureResultsDisputes>,
+    challenge_period: i64,
+    dispute_bond: u64,
+    results_resolver: Pubkey,
+) -> Result<()> {
+    require!(
+        (0..=MAX_CHALLENGE_PERIOD_SECS).contains(&challenge_period),
+        SportsbookError::InvalidChallengePeriod
+    );
+
+    let betting_pool = &mut ctx.accounts.betting_pool;
+    betting_pool.challenge_period = challenge_period;
+    betting_pool.dispute_bond = dispute_bond;
+    betting_pool.results_resolver = results_resolver;
+
+    msg!(
+        "Results disputes: {}s challenge period, bond {}, resolver {}",
+        challenge_period,
+        dispute_bond,
+        results_resolver
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::round_accounting::tests::make_round;
+
+    fn scores(n: usize) -> Vec<MatchScore> {
+        vec![MatchScore { home_goals: 1, away_goals: 0 }; n]
+    }
+
+    #[test]
+    fn test_proposal_mask_collects_distinct_indices() {
+        let round = make_round(10);
+        assert_eq!(proposal_mask(&round, &[0, 3, 9], &scores(3)).unwrap(), 0b10_0000_1001);
+    }
+
+    #[test]
+    fn test_proposal_mask_rejects_invalid_indices() {
+        let round = make_round(10);
+
+        // Duplicate index
+        assert!(proposal_mask(&round, &[2, 2], &scores(2)).is_err());
+        // Beyond the round's match count
+        assert!(proposal_mask(&round, &[10], &scores(1)).is_err());
+        // Empty or mismatched lengths
+        assert!(proposal_mask(&round, &[], &[]).is_err());
+        assert!(proposal_mask(&round, &[0, 1], &scores(1)).is_err());
+    }
+
+    #[test]
+    fn test_proposal_mask_rejects_settled_match() {
+        let mut round = make_round(10);
+        round.match_settled_at[4] = 1_000;
+
+        assert!(proposal_mask(&round, &[4], &scores(1)).is_err());
+        assert!(proposal_mask(&round, &[3, 5], &scores(2)).is_ok());
+    }
+}
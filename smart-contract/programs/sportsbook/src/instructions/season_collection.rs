@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, CreateMasterEditionV3,
+    CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::metadata::mpl_token_metadata::types::{CollectionDetails, DataV2};
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{BettingPool, SeasonNftConfig};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+
+/// Create the Metaplex collection that every season prediction NFT is verified into
+#[derive(Accounts)]
+pub struct InitializeSeasonCollection<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeasonNftConfig::LEN,
+        seeds = [b"season_nft_config", betting_pool.key().as_ref()],
+        bump
+    )]
+    pub season_nft_config: Box<Account<'info, SeasonNftConfig>>,
+
+    /// Collection NFT mint (PDA)
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = betting_pool,
+        mint::freeze_authority = betting_pool,
+        seeds = [b"season_collection", betting_pool.key().as_ref()],
+        bump
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// Betting pool's token account holding the collection NFT
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collection_mint,
+        associated_token::authority = betting_pool
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Collection metadata account
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// Collection master edition account
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeSeasonCollection>,
+    name: String,
+    symbol: String,
+    base_uri: String,
+    collection_uri: String,
+) -> Result<()> {
+    require!(name.len() <= MAX_NFT_NAME_LEN, SportsbookError::NftMetadataTooLong);
+    require!(symbol.len() <= MAX_NFT_SYMBOL_LEN, SportsbookError::NftMetadataTooLong);
+    require!(base_uri.len() <= MAX_NFT_BASE_URI_LEN, SportsbookError::NftMetadataTooLong);
+    require!(collection_uri.len() <= MAX_NFT_URI_LEN, SportsbookError::NftMetadataTooLong);
+
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let collection_mint_key = ctx.accounts.collection_mint.key();
+
+    let seeds = &[
+        b"betting_pool".as_ref(),
+        &[ctx.accounts.betting_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Mint the single collection NFT to the betting pool
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.collection_token_account.to_account_info(),
+        authority: ctx.accounts.betting_pool.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::mint_to(cpi_ctx, 1)?;
+
+    // Sized collection metadata (betting pool is update authority)
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: ctx.accounts.collection_metadata.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        mint_authority: ctx.accounts.betting_pool.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        update_authority: ctx.accounts.betting_pool.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    create_metadata_accounts_v3(
+        cpi_ctx,
+        DataV2 {
+            name,
+            symbol: symbol.clone(),
+            uri: collection_uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+
+    // Master edition with zero supply makes the collection a unique NFT
+    let cpi_accounts = CreateMasterEditionV3 {
+        edition: ctx.accounts.collection_master_edition.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        update_authority: ctx.accounts.betting_pool.to_account_info(),
+        mint_authority: ctx.accounts.betting_pool.to_account_info(),
+        payer: ctx.accounts.authority.to_account_info(),
+        metadata: ctx.accounts.collection_metadata.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    create_master_edition_v3(cpi_ctx, Some(0))?;
+
+    // Record collection and prediction NFT metadata config
+    ctx.accounts.betting_pool.season_nft_collection = collection_mint_key;
+
+    let config = &mut ctx.accounts.season_nft_config;
+    config.betting_pool = betting_pool_key;
+    config.collection_mint = collection_mint_key;
+    config.symbol = symbol;
+    config.base_uri = base_uri;
+    config.bump = ctx.bumps.season_nft_config;
+
+    msg!("Season NFT collection initialized: {}", collection_mint_key);
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{BettingPool, SeasonOutright, OutrightPosition};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::{calculate_outright_stake_odds, scale_token_amount};
+
+#[derive(Accounts)]
+#[instruction(team: u8)]
+pub struct PlaceOutrightBet<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = SeasonOutright::LEN,
+        seeds = [
+            b"season_outright",
+            betting_pool.key().as_ref(),
+            betting_pool.current_season_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub season_outright: Box<Account<'info, SeasonOutright>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = OutrightPosition::LEN,
+        seeds = [
+            b"outright_position",
+            betting_pool.key().as_ref(),
+            betting_pool.current_season_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            &[team]
+        ],
+        bump
+    )]
+    pub outright_position: Box<Account<'info, OutrightPosition>>,
+
+    /// User's token account
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Betting pool's token account (receives stakes, pays winners)
+    #[account(mut)]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury token account (receives fees)
+    #[account(mut)]
+    pub protocol_treasury_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PlaceOutrightBet>,
+    team: u8,
+    amount: u64,
+) -> Result<()> {
+    require!((team as usize) < NUM_TEAMS, SportsbookError::InvalidTeamIndex);
+    require!(amount > 0, SportsbookError::InvalidAmount);
+    let token_decimals = ctx.accounts.betting_pool.token_decimals;
+    require!(
+        amount <= scale_token_amount(MAX_OUTRIGHT_STAKE, token_decimals),
+        SportsbookError::BetExceedsMaximum
+    );
+    require!(
+        !ctx.accounts.betting_pool.season_ended,
+        SportsbookError::SeasonAlreadyEnded
+    );
+
+    // Staking closes with season predictions, before the table gives the winner away
+    let rounds_elapsed = ctx.accounts.betting_pool.next_round_id
+        .saturating_sub(ctx.accounts.betting_pool.season_start_round_id);
+    require!(
+        rounds_elapsed < ctx.accounts.betting_pool.prediction_cutoff_rounds,
+        SportsbookError::OutrightStakingClosed
+    );
+
+    let betting_pool_key = ctx.accounts.betting_pool.key();
+    let betting_pool_info = ctx.accounts.betting_pool.to_account_info();
+    let betting_pool_bump = ctx.accounts.betting_pool.bump;
+    let season_id = ctx.accounts.betting_pool.current_season_id;
+
+    // First stake of the season creates the market
+    if ctx.accounts.season_outright.betting_pool == Pubkey::default() {
+        ctx.accounts.season_outright.betting_pool = betting_pool_key;
+        ctx.accounts.season_outright.season_id = season_id;
+        ctx.accounts.season_outright.bump = ctx.bumps.season_outright;
+    }
+
+    // First stake on this team creates the position
+    if ctx.accounts.outright_position.user == Pubkey::default() {
+        ctx.accounts.outright_position.user = ctx.accounts.user.key();
+        ctx.accounts.outright_position.season_id = season_id;
+        ctx.accounts.outright_position.team = team;
+        ctx.accounts.outright_position.bump = ctx.bumps.outright_position;
+    }
+
+    // Transfer user's stake
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.user_token_account.to_account_info(),
+        to: ctx.accounts.betting_pool_token_account.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::transfer(cpi_ctx, amount)?;
+
+    // Deduct protocol fee
+    let protocol_fee = (amount as u128)
+        .checked_mul(ctx.accounts.betting_pool.protocol_fee_bps as u128)
+        .ok_or(SportsbookError::CalculationOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(SportsbookError::CalculationOverflow)? as u64;
+
+    let stake = amount.saturating_sub(protocol_fee);
+
+    let seeds = &[b"betting_pool".as_ref(), &[betting_pool_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.betting_pool_token_account.to_account_info(),
+        to: ctx.accounts.protocol_treasury_token_account.to_account_info(),
+        authority: betting_pool_info,
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, protocol_fee)?;
+
+    // Lock odds at the stake's average price as it moves the book
+    let locked_odds = calculate_outright_stake_odds(
+        &ctx.accounts.season_outright.team_pools,
+        team as usize,
+        stake,
+        scale_token_amount(OUTRIGHT_VIRTUAL_STAKE_PER_TEAM, token_decimals),
+    );
+
+    let potential_payout = (stake as u128)
+        .checked_mul(locked_odds as u128)
+        .ok_or(SportsbookError::CalculationOverflow)?
+        .checked_div(ODDS_SCALE as u128)
+        .ok_or(SportsbookError::CalculationOverflow)? as u64;
+
+    let outright = &mut ctx.accounts.season_outright;
+    let team_index = team as usize;
+
+    outright.team_pools[team_index] = outright.team_pools[team_index]
+        .checked_add(stake)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+    outright.team_liability[team_index] = outright.team_liability[team_index]
+        .checked_add(potential_payout)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+    outright.total_staked = outright.total_staked
+        .checked_add(stake)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+
+    // Protocol must be able to pay out whichever team wins the league, on top of
+    // everything else already reserved
+    outright.update_reserve(&mut ctx.accounts.betting_pool.outright_reserve);
+    let current_balance = ctx.accounts.betting_pool_token_account.amount;
+    require!(
+        current_balance
+            >= ctx.accounts.betting_pool.outright_reserve
+                .saturating_add(ctx.accounts.betting_pool.cross_round_liability),
+        SportsbookError::InsufficientProtocolLiquidity
+    );
+
+    let position = &mut ctx.accounts.outright_position;
+    position.stake = position.stake
+        .checked_add(stake)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+    position.potential_payout = position.potential_payout
+        .checked_add(potential_payout)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+
+    msg!("Outright stake placed on team {} for season {}", team, season_id);
+    msg!("Amount: {}, After fee: {}", amount, stake);
+    msg!("Locked odds: {}, Potential payout: {}", locked_odds, potential_payout);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimOutrightWinnings<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"season_outright",
+            betting_pool.key().as_ref(),
+            outright_position.season_id.to_le_bytes().as_ref()
+        ],
+        bump = season_outright.bump,
+    )]
+    pub season_outright: Box<Account<'info, SeasonOutright>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"outright_position",
+            betting_pool.key().as_ref(),
+            outright_position.season_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            &[outright_position.team]
+        ],
+        bump = outright_position.bump,
+        constraint = outright_position.user == user.key() @ SportsbookError::NotBettor,
+        constraint = !outright_position.claimed @ SportsbookError::BetAlreadyClaimed,
+    )]
+    pub outright_position: Box<Account<'info, OutrightPosition>>,
+
+    /// Betting pool's token account
+    #[account(mut)]
+    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// User's token account (receives outright winnings)
+    #[account(mut)]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn claim_outright_winnings_handler(ctx: Context<ClaimOutrightWinnings>) -> Result<()> {
+    // The season's market records its winner when the season ends, so positions
+    // stay claimable after later seasons start
+    require!(ctx.accounts.season_outright.settled, SportsbookError::SeasonNotEnded);
+
+    require!(
+        ctx.accounts.outright_position.team == ctx.accounts.season_outright.winning_team,
+        SportsbookError::NotSeasonWinner
+    );
+
+    let payout = ctx.accounts.outright_position.potential_payout;
+    require!(
+        ctx.accounts.betting_pool_token_account.amount >= payout,
+        SportsbookError::InsufficientProtocolLiquidity
+    );
+
+    // Mark as claimed
+    ctx.accounts.outright_position.claimed = true;
+
+    let seeds = &[
+        b"betting_pool".as_ref(),
+        &[ctx.accounts.betting_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.betting_pool_token_account.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.betting_pool.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    token::transfer(cpi_ctx, payout)?;
+
+    ctx.accounts.season_outright.total_paid_out += payout;
+    ctx.accounts.season_outright.update_reserve(&mut ctx.accounts.betting_pool.outright_reserve);
+
+    msg!("Outright winnings claimed!");
+    msg!("User: {}", ctx.accounts.user.key());
+    msg!("Team: {}, Payout: {}", ctx.accounts.outright_position.team, payout);
+
+    Ok(())
+}
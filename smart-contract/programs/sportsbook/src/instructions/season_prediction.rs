@@ -1,7 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
-use crate::state::{BettingPool, SeasonPrediction};
+use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo, Burn};
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, update_metadata_accounts_v2,
+    verify_sized_collection_item, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata,
+    UpdateMetadataAccountsV2, VerifySizedCollectionItem,
+};
+use anchor_spl::metadata::mpl_token_metadata::types::{Collection, DataV2};
+use crate::state::{BettingPool, SeasonPrediction, SeasonResult, SeasonNftConfig};
 use crate::errors::SportsbookError;
+use crate::utils::{
+    calculate_prediction_weight, calculate_weighted_share, split_season_pool,
+    season_nft_name, season_nft_uri, season_nft_champion_name, season_nft_champion_uri,
+};
 
 #[derive(Accounts)]
 #[instruction(predicted_team: u8)]
@@ -29,6 +39,7 @@ pub struct MakeSeasonPrediction<'info> {
         payer = user,
         mint::decimals = 0,
         mint::authority = betting_pool,
+        mint::freeze_authority = betting_pool,
         seeds = [
             b"prediction_nft",
             betting_pool.key().as_ref(),
@@ -48,11 +59,64 @@ pub struct MakeSeasonPrediction<'info> {
     )]
     pub user_nft_account: Box<Account<'info, TokenAccount>>,
 
+    /// Symbol and base URI for prediction NFT metadata
+    #[account(
+        seeds = [b"season_nft_config", betting_pool.key().as_ref()],
+        bump = season_nft_config.bump,
+    )]
+    pub season_nft_config: Box<Account<'info, SeasonNftConfig>>,
+
+    /// Prediction NFT metadata account
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// Prediction NFT master edition account
+    /// CHECK: Created and validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), nft_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub nft_master_edition: UncheckedAccount<'info>,
+
+    /// Season NFT collection mint
+    #[account(
+        address = betting_pool.season_nft_collection @ SportsbookError::SeasonCollectionNotInitialized,
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    /// Collection metadata account (size is incremented on verify)
+    /// CHECK: Validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// Collection master edition account
+    /// CHECK: Validated by the Token Metadata program
+    #[account(
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), collection_mint.key().as_ref(), b"edition"],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -73,6 +137,16 @@ pub fn handler(
         SportsbookError::RoundAlreadySettled // Reusing error
     );
 
+    // Predictions close after the configured number of rounds;
+    // earlier predictions carry more weight in the reward split
+    let rounds_elapsed = ctx.accounts.betting_pool.next_round_id
+        .saturating_sub(ctx.accounts.betting_pool.season_start_round_id);
+    let weight = calculate_prediction_weight(
+        rounds_elapsed,
+        ctx.accounts.betting_pool.prediction_cutoff_rounds,
+    )
+    .ok_or(SportsbookError::PredictionDeadlinePassed)?;
+
     let clock = Clock::get()?;
 
     // Initialize season prediction
@@ -82,8 +156,15 @@ pub fn handler(
     ctx.accounts.season_prediction.nft_mint = ctx.accounts.nft_mint.key();
     ctx.accounts.season_prediction.claimed_reward = false;
     ctx.accounts.season_prediction.predicted_at = clock.unix_timestamp;
+    ctx.accounts.season_prediction.nft_redeemed = false;
+    ctx.accounts.season_prediction.weight = weight;
     ctx.accounts.season_prediction.bump = ctx.bumps.season_prediction;
 
+    let team_weight = &mut ctx.accounts.betting_pool.season_team_weights[predicted_team as usize];
+    *team_weight = team_weight
+        .checked_add(weight)
+        .ok_or(SportsbookError::CalculationOverflow)?;
+
     // Mint NFT to user (1 token, non-fungible)
     let seeds = &[
         b"betting_pool".as_ref(),
@@ -100,10 +181,73 @@ pub fn handler(
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     token::mint_to(cpi_ctx, 1)?;
 
+    // Metadata: season and team in the name, full traits behind the URI
+    let season_id = ctx.accounts.betting_pool.current_season_id;
+    let data = DataV2 {
+        name: season_nft_name(season_id, predicted_team),
+        symbol: ctx.accounts.season_nft_config.symbol.clone(),
+        uri: season_nft_uri(
+            &ctx.accounts.season_nft_config.base_uri,
+            season_id,
+            predicted_team,
+            clock.unix_timestamp,
+        ),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: Some(Collection {
+            verified: false,
+            key: ctx.accounts.collection_mint.key(),
+        }),
+        uses: None,
+    };
+
+    let cpi_accounts = CreateMetadataAccountsV3 {
+        metadata: ctx.accounts.nft_metadata.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        mint_authority: ctx.accounts.betting_pool.to_account_info(),
+        payer: ctx.accounts.user.to_account_info(),
+        update_authority: ctx.accounts.betting_pool.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    create_metadata_accounts_v3(cpi_ctx, data, true, true, None)?;
+
+    // Master edition with zero supply: a true 1/1 NFT
+    let cpi_accounts = CreateMasterEditionV3 {
+        edition: ctx.accounts.nft_master_edition.to_account_info(),
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        update_authority: ctx.accounts.betting_pool.to_account_info(),
+        mint_authority: ctx.accounts.betting_pool.to_account_info(),
+        payer: ctx.accounts.user.to_account_info(),
+        metadata: ctx.accounts.nft_metadata.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    create_master_edition_v3(cpi_ctx, Some(0))?;
+
+    // Verify membership of the season collection (betting pool is collection authority)
+    let cpi_accounts = VerifySizedCollectionItem {
+        payer: ctx.accounts.user.to_account_info(),
+        metadata: ctx.accounts.nft_metadata.to_account_info(),
+        collection_authority: ctx.accounts.betting_pool.to_account_info(),
+        collection_mint: ctx.accounts.collection_mint.to_account_info(),
+        collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    verify_sized_collection_item(cpi_ctx, None)?;
+
     msg!("Season prediction made!");
     msg!("User: {}", ctx.accounts.user.key());
     msg!("Season: {}", ctx.accounts.betting_pool.current_season_id);
     msg!("Predicted team: {}", predicted_team);
+    msg!("Weight: {} ({} rounds elapsed)", weight, rounds_elapsed);
     msg!("NFT minted: {}", ctx.accounts.nft_mint.key());
 
     Ok(())
@@ -120,22 +264,46 @@ pub struct ClaimSeasonReward<'info> {
             b"season_prediction",
             betting_pool.key().as_ref(),
             season_prediction.season_id.to_le_bytes().as_ref(),
-            user.key().as_ref()
+            season_prediction.user.as_ref()
         ],
         bump = season_prediction.bump,
-        constraint = season_prediction.user == user.key() @ SportsbookError::NotBettor,
         constraint = !season_prediction.claimed_reward @ SportsbookError::BetAlreadyClaimed,
     )]
     pub season_prediction: Box<Account<'info, SeasonPrediction>>,
 
-    /// Betting pool's token account
-    #[account(mut)]
-    pub betting_pool_token_account: Box<Account<'info, TokenAccount>>,
+    /// The prediction's season result (exists once that season has ended)
+    #[account(
+        mut,
+        seeds = [
+            b"season_result",
+            betting_pool.key().as_ref(),
+            season_prediction.season_id.to_le_bytes().as_ref()
+        ],
+        bump = season_result.bump,
+    )]
+    pub season_result: Box<Account<'info, SeasonResult>>,
+
+    /// Holder's prediction NFT account (proves ownership of the prediction)
+    #[account(
+        token::mint = season_prediction.nft_mint,
+        token::authority = user,
+        constraint = nft_token_account.amount == 1 @ SportsbookError::NotBettor,
+    )]
+    pub nft_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Season vault (season rewards are paid only from here)
+    #[account(
+        mut,
+        seeds = [b"season_vault", betting_pool.key().as_ref()],
+        bump,
+    )]
+    pub season_vault: Box<Account<'info, TokenAccount>>,
 
     /// User's token account (receives season rewards)
     #[account(mut)]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// Current holder of the prediction NFT
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -144,29 +312,34 @@ pub struct ClaimSeasonReward<'info> {
 
 pub fn claim_season_reward_handler(
     ctx: Context<ClaimSeasonReward>,
-    total_predictors: u64, // Total number of users who predicted correctly
 ) -> Result<()> {
-    // Ensure season has ended
-    require!(
-        ctx.accounts.betting_pool.season_ended,
-        SportsbookError::RoundNotSettled
-    );
+    // The season has ended once its result exists; winner and weights come from it
+    let season_result = &ctx.accounts.season_result;
 
     // Check if user predicted correctly
     require!(
-        ctx.accounts.season_prediction.predicted_team == ctx.accounts.betting_pool.season_winning_team,
+        ctx.accounts.season_prediction.predicted_team == season_result.winning_team,
         SportsbookError::NotBettor // Reusing error - means "not a winner"
     );
 
-    // Calculate user's share of season pool
-    let season_pool = ctx.accounts.betting_pool.season_reward_pool;
+    // Calculate user's share of the season pool as snapshotted at season end
+    let season_pool = season_result.reward_snapshot;
     require!(
         season_pool > 0,
         SportsbookError::InvalidAmount
     );
 
-    // Each correct predictor gets equal share
-    let user_share = season_pool / total_predictors;
+    // Correct predictors split the pool (minus consolation) pro rata by prediction weight
+    let winning_team = season_result.winning_team as usize;
+    let total_weight = season_result.team_weights[winning_team];
+    let all_weight = season_result.team_weights.iter().sum();
+    let (winners_pool, _) = split_season_pool(season_pool, total_weight, all_weight);
+    let user_share = calculate_weighted_share(
+        winners_pool,
+        ctx.accounts.season_prediction.weight,
+        total_weight,
+    )
+    .min(season_result.unclaimed_rewards);
 
     // Mark as claimed
     ctx.accounts.season_prediction.claimed_reward = true;
@@ -179,7 +352,7 @@ pub fn claim_season_reward_handler(
     let signer = &[&seeds[..]];
 
     let cpi_accounts = anchor_spl::token::Transfer {
-        from: ctx.accounts.betting_pool_token_account.to_account_info(),
+        from: ctx.accounts.season_vault.to_account_info(),
         to: ctx.accounts.user_token_account.to_account_info(),
         authority: ctx.accounts.betting_pool.to_account_info(),
     };
@@ -187,12 +360,197 @@ pub fn claim_season_reward_handler(
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     token::transfer(cpi_ctx, user_share)?;
 
-    // Update pool
-    ctx.accounts.betting_pool.season_reward_pool -= user_share;
+    // Update pool and the season's outstanding rewards
+    pay_season_reward(&mut ctx.accounts.betting_pool, &mut ctx.accounts.season_result, user_share);
 
     msg!("Season reward claimed!");
     msg!("User: {}", ctx.accounts.user.key());
+    msg!("Weight: {} of {}", ctx.accounts.season_prediction.weight, total_weight);
     msg!("Share: {}", user_share);
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct RedeemSeasonNft<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"season_prediction",
+            betting_pool.key().as_ref(),
+            season_prediction.season_id.to_le_bytes().as_ref(),
+            season_prediction.user.as_ref()
+        ],
+        bump = season_prediction.bump,
+        constraint = !season_prediction.nft_redeemed @ SportsbookError::BetAlreadyClaimed,
+    )]
+    pub season_prediction: Box<Account<'info, SeasonPrediction>>,
+
+    /// The prediction's season result (exists once that season has ended)
+    #[account(
+        mut,
+        seeds = [
+            b"season_result",
+            betting_pool.key().as_ref(),
+            season_prediction.season_id.to_le_bytes().as_ref()
+        ],
+        bump = season_result.bump,
+    )]
+    pub season_result: Box<Account<'info, SeasonResult>>,
+
+    #[account(
+        seeds = [b"season_nft_config", betting_pool.key().as_ref()],
+        bump = season_nft_config.bump,
+    )]
+    pub season_nft_config: Box<Account<'info, SeasonNftConfig>>,
+
+    /// Prediction NFT mint
+    #[account(mut, address = season_prediction.nft_mint)]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    /// Holder's prediction NFT account (proves ownership, burned if losing)
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = holder,
+        constraint = nft_token_account.amount == 1 @ SportsbookError::NotBettor,
+    )]
+    pub nft_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Prediction NFT metadata account
+    /// CHECK: Validated by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), nft_mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump
+    )]
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// Season vault (pays consolation rewards)
+    #[account(
+        mut,
+        seeds = [b"season_vault", betting_pool.key().as_ref()],
+        bump,
+    )]
+    pub season_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Holder's token account (receives consolation reward)
+    #[account(mut)]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Current holder of the prediction NFT
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+/// Redeem a season prediction NFT once the season has resolved
+///
+/// Correct predictions are upgraded in place to "champion predictor" metadata.
+/// Losing predictions are burned in exchange for a weighted consolation share.
+pub fn redeem_season_nft_handler(ctx: Context<RedeemSeasonNft>) -> Result<()> {
+    let season_id = ctx.accounts.season_prediction.season_id;
+    let team = ctx.accounts.season_prediction.predicted_team;
+    let winning_team = ctx.accounts.season_result.winning_team;
+
+    let seeds = &[
+        b"betting_pool".as_ref(),
+        &[ctx.accounts.betting_pool.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    ctx.accounts.season_prediction.nft_redeemed = true;
+
+    if team == winning_team {
+        // Upgrade metadata to mark the champion predictor
+        let data = DataV2 {
+            name: season_nft_champion_name(season_id, team),
+            symbol: ctx.accounts.season_nft_config.symbol.clone(),
+            uri: season_nft_champion_uri(
+                &ctx.accounts.season_nft_config.base_uri,
+                season_id,
+                team,
+                ctx.accounts.season_prediction.predicted_at,
+            ),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: Some(Collection {
+                verified: true,
+                key: ctx.accounts.season_nft_config.collection_mint,
+            }),
+            uses: None,
+        };
+
+        let cpi_accounts = UpdateMetadataAccountsV2 {
+            metadata: ctx.accounts.nft_metadata.to_account_info(),
+            update_authority: ctx.accounts.betting_pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        update_metadata_accounts_v2(cpi_ctx, None, Some(data), None, None)?;
+
+        msg!("Season {} NFT upgraded: champion predictor (team {})", season_id, team);
+        msg!("Holder: {}", ctx.accounts.holder.key());
+        return Ok(());
+    }
+
+    // Losing prediction: burn the NFT for a consolation share
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.nft_mint.to_account_info(),
+        from: ctx.accounts.nft_token_account.to_account_info(),
+        authority: ctx.accounts.holder.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    token::burn(cpi_ctx, 1)?;
+
+    let team_weights = ctx.accounts.season_result.team_weights;
+    let winning_weight = team_weights[winning_team as usize];
+    let all_weight: u64 = team_weights.iter().sum();
+    let (_, consolation_pool) = split_season_pool(
+        ctx.accounts.season_result.reward_snapshot,
+        winning_weight,
+        all_weight,
+    );
+    let consolation = calculate_weighted_share(
+        consolation_pool,
+        ctx.accounts.season_prediction.weight,
+        all_weight.saturating_sub(winning_weight),
+    )
+    .min(ctx.accounts.season_result.unclaimed_rewards);
+
+    // A burned prediction can never claim the winners' pool
+    ctx.accounts.season_prediction.claimed_reward = true;
+
+    if consolation > 0 {
+        let cpi_accounts = anchor_spl::token::Transfer {
+            from: ctx.accounts.season_vault.to_account_info(),
+            to: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.betting_pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token::transfer(cpi_ctx, consolation)?;
+
+        pay_season_reward(&mut ctx.accounts.betting_pool, &mut ctx.accounts.season_result, consolation);
+    }
+
+    msg!("Season {} NFT burned (team {}, winner {})", season_id, team, winning_team);
+    msg!("Holder: {}", ctx.accounts.holder.key());
+    msg!("Consolation: {}", consolation);
+
+    Ok(())
+}
+
+/// Take a paid season reward out of the pool and out of its season's commitment
+fn pay_season_reward(betting_pool: &mut BettingPool, season_result: &mut SeasonResult, amount: u64) {
+    betting_pool.season_reward_pool = betting_pool.season_reward_pool.saturating_sub(amount);
+    betting_pool.season_rewards_committed = betting_pool.season_rewards_committed.saturating_sub(amount);
+    season_result.unclaimed_rewards = season_result.unclaimed_rewards.saturating_sub(amount);
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::BettingPool;
+use crate::errors::SportsbookError;
+
+/// Create the dedicated vault that holds the season reward pool
+///
+/// Season shares are moved here at round finalization and season rewards are
+/// paid only from here, so betting liquidity is never used for them.
+#[derive(Accounts)]
+pub struct InitializeSeasonVault<'info> {
+    #[account(mut)]
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = token_mint,
+        token::authority = betting_pool,
+        seeds = [b"season_vault", betting_pool.key().as_ref()],
+        bump
+    )]
+    pub season_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = betting_pool.token_mint @ SportsbookError::InvalidTokenAccount)]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeSeasonVault>) -> Result<()> {
+    ctx.accounts.betting_pool.season_vault = ctx.accounts.season_vault.key();
+
+    msg!("Season vault initialized: {}", ctx.accounts.season_vault.key());
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{BettingPool, RoundAccounting, LiquidityPool};
+use crate::state::{BettingPool, RoundAccounting, LiquidityPool, OddsProfile, OddsCurve};
 use crate::errors::SportsbookError;
 use crate::constants::*;
-use crate::utils::{calculate_pseudo_random_seeds, calculate_locked_odds_from_seeds};
+use crate::utils::{
+    calculate_pseudo_random_seeds, calculate_profiled_odds_from_seeds, book_overround_bps, calculate_round_seed,
+    calculate_seeds_from_odds,
+};
 
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
@@ -27,6 +30,13 @@ pub struct SeedRoundPools<'info> {
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
 
+    /// Optional: house odds profile (defaults to the standard linear compression, no re-margin)
+    #[account(
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump = odds_profile.bump,
+    )]
+    pub odds_profile: Option<Account<'info, OddsProfile>>,
+
     /// LP pool's token account
     #[account(mut)]
     pub lp_token_account: Account<'info, TokenAccount>,
@@ -44,19 +54,40 @@ pub struct SeedRoundPools<'info> {
 pub fn handler(ctx: Context<SeedRoundPools>, round_id: u64) -> Result<()> {
     let mut total_seed_amount = 0u64;
 
+    let (curve, overround_bps) = match ctx.accounts.odds_profile {
+        Some(ref profile) => (profile.curve, profile.overround_bps[MARKET_MATCH_RESULT as usize]),
+        None => (OddsCurve::default(), 0),
+    };
+
+    // Size the round's seed from LP capital in the betting mint's units
+    let round_seed = calculate_round_seed(
+        ctx.accounts.liquidity_pool.available_liquidity,
+        ctx.accounts.betting_pool.seed_fraction_bps,
+        ctx.accounts.betting_pool.token_decimals,
+    );
+    let num_matches = ctx.accounts.round_accounting.num_matches as usize;
+    let seed_per_match = round_seed / num_matches as u64;
+    let oracle_odds = ctx.accounts.round_accounting.oracle_odds;
+
     // Seed each match with DIFFERENTIATED amounts based on team matchup
     // For now, using pseudo-random seeding (would integrate with game engine for team IDs)
-    for match_index in 0..MATCHES_PER_ROUND {
+    for match_index in 0..num_matches {
         // In production, these would come from a game engine
         // For now, using match_index as a pseudo team ID
         let home_team_id = (match_index as u64) * 2;
         let away_team_id = (match_index as u64) * 2 + 1;
 
-        let (home_seed, away_seed, draw_seed) = calculate_pseudo_random_seeds(
-            home_team_id,
-            away_team_id,
-            round_id,
-        );
+        // Oracle-priced rounds size each pool to the submitted price instead
+        let (home_seed, away_seed, draw_seed) = if oracle_odds {
+            calculate_seeds_from_odds(&ctx.accounts.round_accounting.locked_odds[match_index], seed_per_match)
+        } else {
+            calculate_pseudo_random_seeds(
+                home_team_id,
+                away_team_id,
+                round_id,
+                seed_per_match,
+            )
+        };
 
         // Update match pool with seeds
         let pool = &mut ctx.accounts.round_accounting.match_pools[match_index];
@@ -68,28 +99,37 @@ pub fn handler(ctx: Context<SeedRoundPools>, round_id: u64) -> Result<()> {
         total_seed_amount += pool.total_pool;
         ctx.accounts.round_accounting.total_bet_volume += pool.total_pool;
 
-        // Lock odds based on seed ratios
-        let (home_odds, away_odds, draw_odds) = calculate_locked_odds_from_seeds(
-            home_seed,
-            away_seed,
-            draw_seed,
-        );
-
+        // Lock odds based on seed ratios, shaped by the odds profile (oracle prices stand as submitted)
         let locked_odds = &mut ctx.accounts.round_accounting.locked_odds[match_index];
-        locked_odds.home_odds = home_odds;
-        locked_odds.away_odds = away_odds;
-        locked_odds.draw_odds = draw_odds;
-        locked_odds.locked = true;
+        if !oracle_odds {
+            let (home_odds, away_odds, draw_odds) = calculate_profiled_odds_from_seeds(
+                home_seed,
+                away_seed,
+                draw_seed,
+                &curve,
+                overround_bps,
+            );
+            locked_odds.home_odds = home_odds;
+            locked_odds.away_odds = away_odds;
+            locked_odds.draw_odds = draw_odds;
+            locked_odds.locked = true;
+        }
+        let (home_odds, away_odds, draw_odds) = (locked_odds.home_odds, locked_odds.away_odds, locked_odds.draw_odds);
+
+        // Record the margin the locked book actually carries (band clamps can move it off target)
+        let book_overround = book_overround_bps(&[home_odds, away_odds, draw_odds]).min(u16::MAX as u64) as u16;
+        ctx.accounts.round_accounting.book_overround_bps[match_index] = book_overround;
 
         msg!(
-            "Match {}: Seeded with {}/{}/{} tokens, Locked odds: {}/{}/{}",
+            "Match {}: Seeded with {}/{}/{} tokens, Locked odds: {}/{}/{}, overround {}bps",
             match_index,
             home_seed,
             away_seed,
             draw_seed,
             home_odds,
             away_odds,
-            draw_odds
+            draw_odds,
+            book_overround
         );
     }
 
@@ -134,3 +174,26 @@ pub fn handler(ctx: Context<SeedRoundPools>, round_id: u64) -> Result<()> {
 
     Ok(())
 }
+
+#[derive(Accounts)]
+pub struct SetSeedFraction<'info> {
+    #[account(mut)]
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Set the share of available LP liquidity seeded into each round from now on
+pub fn set_seed_fraction_handler(ctx: Context<SetSeedFraction>, seed_fraction_bps: u16) -> Result<()> {
+    require!(
+        seed_fraction_bps <= MAX_SEED_FRACTION_BPS,
+        SportsbookError::SeedFractionTooHigh
+    );
+
+    ctx.accounts.betting_pool.seed_fraction_bps = seed_fraction_bps;
+
+    msg!("Seed fraction set to {}bps of available LP liquidity", seed_fraction_bps);
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+use crate::state::{BettingPool, RoundAccounting, OddsProfile, MatchOdds};
+use crate::errors::SportsbookError;
+use crate::constants::*;
+use crate::utils::{is_valid_book, verify_ed25519_signature};
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SetRoundOdds<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.seeded @ SportsbookError::RoundAlreadySeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    /// Optional: house odds profile (its odds band and 1X2 overround bound the submitted prices)
+    #[account(
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump = odds_profile.bump,
+    )]
+    pub odds_profile: Option<Box<Account<'info, OddsProfile>>>,
+
+    #[account(constraint = oracle.key() == betting_pool.odds_oracle @ SportsbookError::NotOddsOracle)]
+    pub oracle: Signer<'info>,
+}
+
+/// Price a round from the odds oracle before it is seeded
+///
+/// Seeding then keeps these prices and sizes each outcome's seed pool to match them
+pub fn handler(
+    ctx: Context<SetRoundOdds>,
+    round_id: u64,
+    sequence: u64,
+    match_odds: Vec<MatchOdds>,
+) -> Result<()> {
+    // A cleared oracle (Pubkey::default()) means no oracle pricing at all
+    require!(
+        ctx.accounts.betting_pool.odds_oracle != Pubkey::default(),
+        SportsbookError::NotOddsOracle
+    );
+
+    apply_round_odds(
+        &mut ctx.accounts.round_accounting,
+        ctx.accounts.odds_profile.as_deref(),
+        sequence,
+        &match_odds,
+    )?;
+
+    msg!("Round {} priced by odds oracle {}", round_id, ctx.accounts.oracle.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct SetRoundOddsSigned<'info> {
+    pub betting_pool: Box<Account<'info, BettingPool>>,
+
+    #[account(
+        mut,
+        seeds = [b"round", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump = round_accounting.bump,
+        constraint = !round_accounting.seeded @ SportsbookError::RoundAlreadySeeded,
+        constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+    )]
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
+
+    /// Optional: house odds profile (its odds band and 1X2 overround bound the submitted prices)
+    #[account(
+        seeds = [b"odds_profile", betting_pool.key().as_ref()],
+        bump = odds_profile.bump,
+    )]
+    pub odds_profile: Option<Box<Account<'info, OddsProfile>>>,
+
+    /// Instructions sysvar (the oracle's ed25519 signature is the preceding instruction)
+    /// CHECK: address is checked against the instructions sysvar ID
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Anyone may relay a payload the oracle signed
+    pub relayer: Signer<'info>,
+}
+
+/// Price a round from an odds payload signed off-chain by the odds oracle
+///
+/// The transaction must carry an ed25519 program instruction, immediately before this
+/// one, over `round_odds_message(betting_pool, round_id, sequence, match_odds)`. The sequence
+/// must exceed the last one applied, so an older signed price set can't be replayed over a newer one
+pub fn set_round_odds_signed_handler(
+    ctx: Context<SetRoundOddsSigned>,
+    round_id: u64,
+    sequence: u64,
+    match_odds: Vec<MatchOdds>,
+) -> Result<()> {
+    // All-zero key bytes are a small-order ed25519 point: never verify against a cleared oracle
+    require!(
+        ctx.accounts.betting_pool.odds_oracle != Pubkey::default(),
+        SportsbookError::NotOddsOracle
+    );
+
+    let message = round_odds_message(&ctx.accounts.betting_pool.key(), round_id, sequence, &match_odds);
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.betting_pool.odds_oracle,
+        &message,
+    )?;
+
+    apply_round_odds(
+        &mut ctx.accounts.round_accounting,
+        ctx.accounts.odds_profile.as_deref(),
+        sequence,
+        &match_odds,
+    )?;
+
+    msg!("Round {} priced from signed oracle payload (sequence {})", round_id, sequence);
+
+    Ok(())
+}
+
+/// Payload the odds oracle signs:
+/// prefix ‖ betting pool ‖ round id ‖ sequence ‖ (home, away, draw) per match
+pub fn round_odds_message(
+    betting_pool: &Pubkey,
+    round_id: u64,
+    sequence: u64,
+    match_odds: &[MatchOdds],
+) -> Vec<u8> {
+    let mut message = Vec::with_capacity(ROUND_ODDS_MESSAGE_PREFIX.len() + 48 + match_odds.len() * 24);
+    message.extend_from_slice(ROUND_ODDS_MESSAGE_PREFIX);
+    message.extend_from_slice(betting_pool.as_ref());
+    message.extend_from_slice(&round_id.to_le_bytes());
+    message.extend_from_slice(&sequence.to_le_bytes());
+    for odds in match_odds {
+        message.extend_from_slice(&odds.home_odds.to_le_bytes());
+        message.extend_from_slice(&odds.away_odds.to_le_bytes());
+        message.extend_from_slice(&odds.draw_odds.to_le_bytes());
+    }
+    message
+}
+
+/// Validate every match's book and lock it on the round
+fn apply_round_odds(
+    round_accounting: &mut RoundAccounting,
+    odds_profile: Option<&Account<OddsProfile>>,
+    sequence: u64,
+    match_odds: &[MatchOdds],
+) -> Result<()> {
+    require!(sequence > round_accounting.odds_sequence, SportsbookError::StaleOddsSequence);
+    require!(
+        match_odds.len() == round_accounting.num_matches as usize,
+        SportsbookError::ArrayLengthMismatch
+    );
+
+    let (min_odds, max_odds, min_overround_bps) = match odds_profile {
+        Some(profile) => (
+            profile.curve.min_odds,
+            profile.curve.max_odds,
+            profile.overround_bps[MARKET_MATCH_RESULT as usize],
+        ),
+        None => (MIN_COMPRESSED_ODDS, MAX_COMPRESSED_ODDS, 0),
+    };
+
+    for (match_index, odds) in match_odds.iter().enumerate() {
+        let book = [odds.home_odds, odds.away_odds, odds.draw_odds];
+        require!(
+            is_valid_book(&book, min_odds, max_odds, min_overround_bps),
+            SportsbookError::InvalidRoundOdds
+        );
+
+        let locked_odds = &mut round_accounting.locked_odds[match_index];
+        locked_odds.home_odds = odds.home_odds;
+        locked_odds.away_odds = odds.away_odds;
+        locked_odds.draw_odds = odds.draw_odds;
+        locked_odds.locked = true;
+    }
+
+    round_accounting.oracle_odds = true;
+    round_accounting.odds_sequence = sequence;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOddsOracle<'info> {
+    #[account(mut)]
+    pub betting_pool: Account<'info, BettingPool>,
+
+    #[account(constraint = authority.key() == betting_pool.authority @ SportsbookError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+}
+
+/// Designate the odds oracle (Pubkey::default() removes it)
+pub fn set_odds_oracle_handler(ctx: Context<SetOddsOracle>, odds_oracle: Pubkey) -> Result<()> {
+    ctx.accounts.betting_pool.odds_oracle = odds_oracle;
+
+    msg!("Odds oracle set to {}", odds_oracle);
+
+    Ok(())
+}
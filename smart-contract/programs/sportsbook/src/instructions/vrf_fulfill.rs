@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::{BettingPool, RoundAccounting};
-use crate::vrf::{VrfRequest, extract_match_results};
+use crate::vrf::{VrfRequest, extract_match_scores, extract_match_results};
 use crate::errors::SportsbookError;
+use crate::constants::{ROUND_SIMULATED, VRF_BYTES_PER_MATCH};
 
 /// Fulfill VRF request and settle round with random results
 ///
@@ -19,8 +20,9 @@ pub struct FulfillVrfRequest<'info> {
         bump = round_accounting.bump,
         constraint = round_accounting.seeded @ SportsbookError::RoundNotSeeded,
         constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = round_accounting.round_kind == ROUND_SIMULATED @ SportsbookError::InvalidRoundKind,
     )]
-    pub round_accounting: Account<'info, RoundAccounting>,
+    pub round_accounting: Box<Account<'info, RoundAccounting>>,
 
     #[account(
         mut,
@@ -48,23 +50,39 @@ pub fn handler(ctx: Context<FulfillVrfRequest>, round_id: u64) -> Result<()> {
     // let result_buffer = vrf_account_data.get_result()?;
     //
     // // Verify the result is valid
-    // require!(result_buffer.len() >= 320, SportsbookError::InvalidVrfResult);
+    // let randomness_len = ctx.accounts.vrf_request.randomness.len();
+    // require!(result_buffer.len() >= randomness_len, SportsbookError::InvalidVrfResult);
     //
     // // Copy randomness to our account
-    // ctx.accounts.vrf_request.randomness.copy_from_slice(&result_buffer[0..320]);
+    // ctx.accounts.vrf_request.randomness.copy_from_slice(&result_buffer[..randomness_len]);
     // ```
 
     // For testing purposes, generate deterministic "randomness" from round_id
     // In production, this would come from Switchboard VRF
-    let mut test_randomness = [0u8; 320];
-    for i in 0..320 {
-        test_randomness[i] = ((round_id as usize + i) % 256) as u8;
-    }
+    let randomness_len = ctx.accounts.round_accounting.num_matches as usize * VRF_BYTES_PER_MATCH;
+    let test_randomness: Vec<u8> = (0..randomness_len)
+        .map(|i| ((round_id as usize + i) % 256) as u8)
+        .collect();
     ctx.accounts.vrf_request.randomness = test_randomness;
 
-    // Extract match results from randomness
-    let match_results = extract_match_results(&ctx.accounts.vrf_request.randomness);
-    ctx.accounts.vrf_request.match_results = match_results;
+    // Simulate scorelines from randomness, driven by the round's locked odds
+    let match_scores = extract_match_scores(
+        &ctx.accounts.vrf_request.randomness,
+        &ctx.accounts.round_accounting.locked_odds,
+    );
+    ctx.accounts.vrf_request.match_scores = match_scores.clone();
+
+    // Only the requested matches are drawn; the rest of the buffer is discarded
+    let requested_matches = ctx.accounts.vrf_request.requested_matches;
+    for (i, score) in match_scores.iter().enumerate() {
+        if requested_matches & (1 << i) != 0 {
+            ctx.accounts.round_accounting.match_scores[i] = *score;
+        }
+    }
+
+    // 1X2 results follow from the scorelines
+    let match_results = extract_match_results(&match_scores);
+    ctx.accounts.vrf_request.match_results = match_results.clone();
 
     // Mark VRF request as fulfilled
     ctx.accounts.vrf_request.fulfilled = true;
@@ -72,6 +90,7 @@ pub fn handler(ctx: Context<FulfillVrfRequest>, round_id: u64) -> Result<()> {
 
     msg!("VRF request fulfilled for round {}", round_id);
     msg!("Match results: {:?}", match_results);
+    msg!("Match scores: {:?}", match_scores);
 
     Ok(())
 }
@@ -2,11 +2,12 @@ use anchor_lang::prelude::*;
 use crate::state::{BettingPool, RoundAccounting};
 use crate::vrf::VrfRequest;
 use crate::errors::SportsbookError;
+use crate::constants::{ROUND_SIMULATED, VRF_BYTES_PER_MATCH};
 
-/// Request VRF randomness for a round
+/// Request VRF randomness for some of a round's matches
 ///
-/// This creates a VRF request account and initiates the randomness request
-/// to the Switchboard oracle network.
+/// This creates (or reuses, once fulfilled) the round's VRF request account and
+/// initiates the randomness request to the Switchboard oracle network.
 #[derive(Accounts)]
 #[instruction(round_id: u64)]
 pub struct RequestVrfRandomness<'info> {
@@ -19,13 +20,14 @@ pub struct RequestVrfRandomness<'info> {
         bump = round_accounting.bump,
         constraint = round_accounting.seeded @ SportsbookError::RoundNotSeeded,
         constraint = !round_accounting.settled @ SportsbookError::RoundAlreadySettled,
+        constraint = round_accounting.round_kind == ROUND_SIMULATED @ SportsbookError::InvalidRoundKind,
     )]
     pub round_accounting: Box<Account<'info, RoundAccounting>>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
-        space = VrfRequest::LEN,
+        space = VrfRequest::space(round_accounting.num_matches as usize),
         seeds = [b"vrf_request", betting_pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
         bump
     )]
@@ -82,17 +84,44 @@ pub struct RequestVrfRandomness<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<RequestVrfRandomness>, round_id: u64) -> Result<()> {
+pub fn handler(ctx: Context<RequestVrfRandomness>, round_id: u64, match_indices: Vec<u8>) -> Result<()> {
+    // Matches are drawn in batches as they "finish"; one request is in flight at a time
+    require!(
+        ctx.accounts.vrf_request.requested_matches == 0 || ctx.accounts.vrf_request.fulfilled,
+        SportsbookError::VrfRequestPending
+    );
+    require!(!match_indices.is_empty(), SportsbookError::ArrayLengthMismatch);
+
+    let round_accounting = &mut ctx.accounts.round_accounting;
+    let mut requested_matches = 0u32;
+    for &match_index in match_indices.iter() {
+        require!(
+            match_index < round_accounting.num_matches && requested_matches & (1 << match_index) == 0,
+            SportsbookError::InvalidMatchIndex
+        );
+        require!(
+            !round_accounting.match_result_in(match_index as usize),
+            SportsbookError::RandomnessAlreadyRequested
+        );
+        requested_matches |= 1 << match_index;
+    }
+
+    // Requesting randomness closes the matches to betting
+    round_accounting.randomness_requested |= requested_matches;
+
     let vrf_request = &mut ctx.accounts.vrf_request;
 
     // Initialize VRF request
     vrf_request.round_id = round_id;
     vrf_request.vrf_account = ctx.accounts.switchboard_vrf.key();
     vrf_request.request_time = Clock::get()?.unix_timestamp;
+    vrf_request.requested_matches = requested_matches;
     vrf_request.fulfilled = false;
     vrf_request.fulfillment_time = 0;
-    vrf_request.randomness = [0u8; 320];
-    vrf_request.match_results = [0u8; 10];
+    let num_matches = round_accounting.num_matches as usize;
+    vrf_request.randomness = vec![0u8; num_matches * VRF_BYTES_PER_MATCH];
+    vrf_request.match_results = vec![0u8; num_matches];
+    vrf_request.match_scores = vec![Default::default(); num_matches];
     vrf_request.bump = ctx.bumps.vrf_request;
 
     // NOTE: In production, this would make a CPI call to Switchboard
@@ -120,7 +149,7 @@ pub fn handler(ctx: Context<RequestVrfRandomness>, round_id: u64) -> Result<()>
     // )?;
     // ```
 
-    msg!("VRF randomness requested for round {}", round_id);
+    msg!("VRF randomness requested for round {}, matches {:?}", round_id, match_indices);
     msg!("VRF account: {}", ctx.accounts.switchboard_vrf.key());
 
     Ok(())
@@ -27,12 +27,69 @@ pub mod sportsbook {
         instructions::initialize::handler(ctx, protocol_fee_bps, winner_share_bps, season_pool_share_bps)
     }
 
-    /// Initialize a new round with seeded match pools
+    /// Initialize a new round of `num_matches` matches (fixed-odds or parimutuel pricing)
     pub fn initialize_round(
         ctx: Context<InitializeRound>,
         round_id: u64,
+        pricing_mode: u8,
+        num_matches: u8,
     ) -> Result<()> {
-        instructions::initialize_round::handler(ctx, round_id)
+        instructions::initialize_round::handler(ctx, round_id, pricing_mode, num_matches)
+    }
+
+    /// Set each match's betting cutoff so the round's matches close individually
+    pub fn set_betting_cutoffs(
+        ctx: Context<SetBettingCutoffs>,
+        round_id: u64,
+        cutoffs: Vec<i64>,
+    ) -> Result<()> {
+        instructions::initialize_round::set_betting_cutoffs_handler(ctx, round_id, cutoffs)
+    }
+
+    /// Designate the odds oracle allowed to price rounds before seeding
+    pub fn set_odds_oracle(
+        ctx: Context<SetOddsOracle>,
+        odds_oracle: Pubkey,
+    ) -> Result<()> {
+        instructions::set_round_odds::set_odds_oracle_handler(ctx, odds_oracle)
+    }
+
+    /// Submit per-match 1X2 prices for a round as the odds oracle (before seeding)
+    pub fn set_round_odds(
+        ctx: Context<SetRoundOdds>,
+        round_id: u64,
+        sequence: u64,
+        match_odds: Vec<MatchOdds>,
+    ) -> Result<()> {
+        instructions::set_round_odds::handler(ctx, round_id, sequence, match_odds)
+    }
+
+    /// Relay round prices signed by the odds oracle (verified via the ed25519 program)
+    pub fn set_round_odds_signed(
+        ctx: Context<SetRoundOddsSigned>,
+        round_id: u64,
+        sequence: u64,
+        match_odds: Vec<MatchOdds>,
+    ) -> Result<()> {
+        instructions::set_round_odds::set_round_odds_signed_handler(ctx, round_id, sequence, match_odds)
+    }
+
+    /// Set the results oracle keys and M-of-N threshold for fixture rounds
+    pub fn configure_oracle_set(
+        ctx: Context<ConfigureOracleSet>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::fixtures::configure_oracle_set_handler(ctx, signers, threshold)
+    }
+
+    /// Register real-world fixtures and kickoff times on a round before it is seeded
+    pub fn register_fixtures(
+        ctx: Context<RegisterFixtures>,
+        round_id: u64,
+        fixtures: Vec<Fixture>,
+    ) -> Result<()> {
+        instructions::fixtures::register_fixtures_handler(ctx, round_id, fixtures)
     }
 
     /// Seed match pools with differentiated amounts based on team matchup
@@ -43,35 +100,178 @@ pub mod sportsbook {
         instructions::seed_round::handler(ctx, round_id)
     }
 
-    /// Place a bet on multiple match outcomes (parlay betting)
-    pub fn place_bet(
-        ctx: Context<PlaceBet>,
+    /// Set the share of available LP liquidity seeded into each round
+    pub fn set_seed_fraction(
+        ctx: Context<SetSeedFraction>,
+        seed_fraction_bps: u16,
+    ) -> Result<()> {
+        instructions::seed_round::set_seed_fraction_handler(ctx, seed_fraction_bps)
+    }
+
+    /// Open a market on one match of a seeded round, locking its odds
+    pub fn create_market(
+        ctx: Context<CreateMarket>,
+        round_id: u64,
+        match_index: u8,
+        market_type: u8,
+    ) -> Result<()> {
+        instructions::create_market::handler(ctx, round_id, match_index, market_type)
+    }
+
+    /// Create the house odds profile (compression curve and per-market overround)
+    pub fn initialize_odds_profile(
+        ctx: Context<InitializeOddsProfile>,
+        curve: OddsCurve,
+        overround_bps: [u16; constants::NUM_MARKET_TYPES],
+    ) -> Result<()> {
+        instructions::odds_profile::handler(ctx, curve, overround_bps)
+    }
+
+    /// Retune the house odds profile for rounds seeded from now on
+    pub fn update_odds_profile(
+        ctx: Context<UpdateOddsProfile>,
+        curve: OddsCurve,
+        overround_bps: [u16; constants::NUM_MARKET_TYPES],
+    ) -> Result<()> {
+        instructions::odds_profile::update_odds_profile_handler(ctx, curve, overround_bps)
+    }
+
+    /// Set how far market prices may move from their seeded price on exposure
+    pub fn set_reprice_band(
+        ctx: Context<SetRepriceBand>,
+        band_bps: u16,
+    ) -> Result<()> {
+        instructions::create_market::set_reprice_band_handler(ctx, band_bps)
+    }
+
+    /// Place a bet on market selections (single, accumulator or system bet), one market account per leg
+    pub fn place_bet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PlaceBet<'info>>,
         round_id: u64,
-        match_indices: Vec<u8>,
         outcomes: Vec<u8>,
+        system_sizes: u16,
         amount: u64,
     ) -> Result<()> {
-        instructions::place_bet::handler(ctx, round_id, match_indices, outcomes, amount)
+        instructions::place_bet::handler(ctx, round_id, outcomes, system_sizes, amount)
+    }
+
+    /// Cash a bet out before its round settles, at a quote from current market odds
+    pub fn cash_out<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CashOut<'info>>,
+        bet_id: u64,
+        min_amount: u64,
+    ) -> Result<()> {
+        instructions::cash_out::handler(ctx, bet_id, min_amount)
+    }
+
+    /// Cash out a fraction (bps) of a bet's stake, leaving the rest riding
+    pub fn partial_cash_out<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PartialCashOut<'info>>,
+        bet_id: u64,
+        fraction_bps: u16,
+        min_amount: u64,
+    ) -> Result<()> {
+        instructions::cash_out::partial_cash_out_handler(ctx, bet_id, fraction_bps, min_amount)
+    }
+
+    /// Remove a leg from an open bet, or replace it, before the round settles
+    pub fn edit_bet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EditBet<'info>>,
+        bet_id: u64,
+        leg_index: u8,
+        new_outcome: Option<u8>,
+    ) -> Result<()> {
+        instructions::edit_bet::handler(ctx, bet_id, leg_index, new_outcome)
+    }
+
+    /// Set the house margin taken off cash-out quotes
+    pub fn set_cash_out_margin(
+        ctx: Context<SetCashOutMargin>,
+        margin_bps: u16,
+    ) -> Result<()> {
+        instructions::cash_out::set_cash_out_margin_handler(ctx, margin_bps)
+    }
+
+    /// Propose scorelines for some of a simulated round's matches, opening the challenge period
+    pub fn propose_results(
+        ctx: Context<ProposeResults>,
+        round_id: u64,
+        match_indices: Vec<u8>,
+        match_scores: Vec<MatchScore>,
+    ) -> Result<()> {
+        instructions::results::handler(ctx, round_id, match_indices, match_scores)
+    }
+
+    /// Propose fixture results attested by M-of-N oracle signatures (ed25519 program)
+    pub fn attest_fixture_results(
+        ctx: Context<AttestFixtureResults>,
+        round_id: u64,
+        match_indices: Vec<u8>,
+        match_scores: Vec<MatchScore>,
+    ) -> Result<()> {
+        instructions::fixtures::attest_fixture_results_handler(ctx, round_id, match_indices, match_scores)
+    }
+
+    /// Dispute a round's proposed results by posting the dispute bond
+    pub fn dispute_results(
+        ctx: Context<DisputeResults>,
+        round_id: u64,
+    ) -> Result<()> {
+        instructions::results::dispute_results_handler(ctx, round_id)
+    }
+
+    /// Rule on a dispute: correct the results (refunding the bond) or uphold them
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        round_id: u64,
+        corrected_scores: Option<Vec<MatchScore>>,
+    ) -> Result<()> {
+        instructions::results::resolve_dispute_handler(ctx, round_id, corrected_scores)
+    }
+
+    /// Settle the proposed matches once the challenge period has passed
+    pub fn finalize_results(
+        ctx: Context<FinalizeResults>,
+        round_id: u64,
+    ) -> Result<()> {
+        instructions::results::finalize_results_handler(ctx, round_id)
+    }
+
+    /// Set the results challenge period, dispute bond and resolver
+    pub fn configure_results_disputes(
+        ctx: Context<ConfigureResultsDisputes>,
+        challenge_period: i64,
+        dispute_bond: u64,
+        results_resolver: Pubkey,
+    ) -> Result<()> {
+        instructions::results::configure_results_disputes_handler(ctx, challenge_period, dispute_bond, results_resolver)
     }
 
-    /// Settle round after VRF generates results
-    pub fn settle_round(
-        ctx: Context<SettleRound>,
+    /// Resolve a market against its settled match score
+    pub fn resolve_market(
+        ctx: Context<ResolveMarket>,
         round_id: u64,
-        match_results: Vec<u8>,
     ) -> Result<()> {
-        instructions::settle_round::handler(ctx, round_id, match_results)
+        instructions::resolve_market::handler(ctx, round_id)
     }
 
     /// Claim winnings for a bet (pull pattern)
-    pub fn claim_winnings(
-        ctx: Context<ClaimWinnings>,
+    pub fn claim_winnings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimWinnings<'info>>,
         bet_id: u64,
         min_payout: u64,
     ) -> Result<()> {
         instructions::claim_winnings::handler(ctx, bet_id, min_payout)
     }
 
+    /// Mark a bet that has already lost dead, releasing its open legs' liability
+    pub fn mark_bet_dead<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MarkBetDead<'info>>,
+        bet_id: u64,
+    ) -> Result<()> {
+        instructions::mark_bet_dead::handler(ctx, bet_id)
+    }
+
     /// Finalize round revenue distribution
     pub fn finalize_round_revenue(
         ctx: Context<FinalizeRoundRevenue>,
@@ -80,12 +280,13 @@ pub mod sportsbook {
         instructions::finalize_revenue::handler(ctx, round_id)
     }
 
-    /// Request VRF randomness for a round
+    /// Request VRF randomness for some of a round's matches, closing them to betting
     pub fn request_vrf_randomness(
         ctx: Context<RequestVrfRandomness>,
         round_id: u64,
+        match_indices: Vec<u8>,
     ) -> Result<()> {
-        instructions::vrf_request::handler(ctx, round_id)
+        instructions::vrf_request::handler(ctx, round_id, match_indices)
     }
 
     /// Fulfill VRF request and extract match results
@@ -96,6 +297,24 @@ pub mod sportsbook {
         instructions::vrf_fulfill::handler(ctx, round_id)
     }
 
+    /// Create the dedicated season reward vault
+    pub fn initialize_season_vault(
+        ctx: Context<InitializeSeasonVault>,
+    ) -> Result<()> {
+        instructions::season_vault::handler(ctx)
+    }
+
+    /// Create the Metaplex collection for season prediction NFTs
+    pub fn initialize_season_collection(
+        ctx: Context<InitializeSeasonCollection>,
+        name: String,
+        symbol: String,
+        base_uri: String,
+        collection_uri: String,
+    ) -> Result<()> {
+        instructions::season_collection::handler(ctx, name, symbol, base_uri, collection_uri)
+    }
+
     /// Make a season prediction and receive commemorative NFT
     pub fn make_season_prediction(
         ctx: Context<MakeSeasonPrediction>,
@@ -107,9 +326,31 @@ pub mod sportsbook {
     /// Claim season reward for correct prediction
     pub fn claim_season_reward(
         ctx: Context<ClaimSeasonReward>,
-        total_predictors: u64,
     ) -> Result<()> {
-        instructions::season_prediction::claim_season_reward_handler(ctx, total_predictors)
+        instructions::season_prediction::claim_season_reward_handler(ctx)
+    }
+
+    /// Stake tokens on a team to win the current season at locked odds
+    pub fn place_outright_bet(
+        ctx: Context<PlaceOutrightBet>,
+        team: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::season_outright::handler(ctx, team, amount)
+    }
+
+    /// Claim winnings for an outright position on the season winner
+    pub fn claim_outright_winnings(
+        ctx: Context<ClaimOutrightWinnings>,
+    ) -> Result<()> {
+        instructions::season_outright::claim_outright_winnings_handler(ctx)
+    }
+
+    /// Redeem a season prediction NFT: upgrade winners, burn losers for consolation
+    pub fn redeem_season_nft(
+        ctx: Context<RedeemSeasonNft>,
+    ) -> Result<()> {
+        instructions::season_prediction::redeem_season_nft_handler(ctx)
     }
 
     /// End the current season and set winning team
@@ -123,7 +364,8 @@ pub mod sportsbook {
     /// Start a new season
     pub fn start_new_season(
         ctx: Context<StartNewSeason>,
+        prediction_cutoff_rounds: u64,
     ) -> Result<()> {
-        instructions::end_season::start_new_season_handler(ctx)
+        instructions::end_season::start_new_season_handler(ctx, prediction_cutoff_rounds)
     }
 }
@@ -2,12 +2,24 @@ pub mod betting_pool;
 pub mod round_accounting;
 pub mod bet;
 pub mod match_pool;
+pub mod market;
+pub mod odds_profile;
+pub mod oracle_set;
+pub mod results_dispute;
 pub mod liquidity_pool;
 pub mod season_prediction;
+pub mod season_outright;
+pub mod season_nft_config;
 
 pub use betting_pool::*;
 pub use round_accounting::*;
 pub use bet::*;
 pub use match_pool::*;
+pub use market::*;
+pub use odds_profile::*;
+pub use oracle_set::*;
+pub use results_dispute::*;
 pub use liquidity_pool::*;
 pub use season_prediction::*;
+pub use season_outright::*;
+pub use season_nft_config::*;
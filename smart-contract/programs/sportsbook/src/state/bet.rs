@@ -1,25 +1,45 @@
 use anchor_lang::prelude::*;
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::SportsbookError;
 
 /// Individual prediction for a single match within a bet
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
 pub struct Prediction {
-    /// Match index (0-9)
+    /// Round of the leg's match (cross-round accumulators mix rounds)
+    pub round_id: u64,
+
+    /// Match index within its round
     pub match_index: u8,
 
-    /// Predicted outcome (1=HOME_WIN, 2=AWAY_WIN, 3=DRAW)
+    /// Market type (0=MATCH_RESULT, 1=TOTAL_GOALS, 2=CORRECT_SCORE,
+    /// 3=DOUBLE_CHANCE, 4=DRAW_NO_BET, 5=BOTH_TEAMS_TO_SCORE, 6=ASIAN_HANDICAP)
+    pub market_type: u8,
+
+    /// Predicted outcome
+    /// MATCH_RESULT: 1=HOME_WIN, 2=AWAY_WIN, 3=DRAW
+    /// TOTAL_GOALS: 1=OVER, 2=UNDER
+    /// CORRECT_SCORE: 1 + home_goals × 4 + away_goals (0-3 each), 17=ANY_OTHER
+    /// DOUBLE_CHANCE: 1=1X, 2=X2, 3=12
+    /// DRAW_NO_BET: 1=HOME, 2=AWAY (void on a draw)
+    /// BOTH_TEAMS_TO_SCORE: 1=YES, 2=NO
+    /// ASIAN_HANDICAP: 1 + line_index × 2 + side (side 0=HOME, 1=AWAY)
     pub predicted_outcome: u8,
 
     /// Amount allocated to this pool
     pub amount_in_pool: u64,
+
+    /// Odds the leg was accepted at (scaled by 1e9); settlement only ever uses this snapshot
+    pub locked_odds: u64,
 }
 
-/// A bet placed by a user (parlay or single bet)
+/// A bet placed by a user (single, accumulator or system bet)
 #[account]
 pub struct Bet {
     /// Bettor's public key
     pub bettor: Pubkey,
 
-    /// Round ID this bet is for
+    /// Round the bet was placed in (its stake and payout are accounted there;
+    /// each leg carries its own round)
     pub round_id: u64,
 
     /// Bet ID (unique identifier)
@@ -43,8 +63,15 @@ pub struct Bet {
     /// Number of predictions in this bet
     pub num_predictions: u8,
 
-    /// Predictions (max 10 matches)
-    pub predictions: [Prediction; 10],
+    /// Predictions, one per leg (at most MAX_LEGS_PER_BET)
+    pub predictions: Vec<Prediction>,
+
+    /// System bet combination sizes: bit k-1 set = every k-fold of the legs
+    /// is included, stake split evenly across combinations (0 = accumulator)
+    pub system_sizes: u16,
+
+    /// Locked parlay multiplier per system combination size (index = size - 1, one per leg)
+    pub system_multipliers: Vec<u64>,
 
     /// Has round been settled?
     pub settled: bool,
@@ -59,12 +86,17 @@ pub struct Bet {
     /// If claimed via bounty, this is the claimer's address
     pub bounty_claimer: Option<Pubkey>,
 
+    /// Max payout reserved in the betting pool while a cross-round bet is open (0 otherwise)
+    pub reserved_liability: u64,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
 
 impl Bet {
-    pub const LEN: usize = 8 + // discriminator
+    /// Account size for a bet of `num_legs` legs (edits can only keep or shrink the leg count)
+    pub fn space(num_legs: usize) -> usize {
+        8 + // discriminator
         32 + // bettor
         8 +  // round_id
         8 +  // bet_id
@@ -74,14 +106,62 @@ impl Bet {
         8 +  // bonus
         8 +  // locked_multiplier
         1 +  // num_predictions
-        (10 * 17) + // predictions (10 predictions * 17 bytes each)
+        (4 + num_legs * 34) + // predictions (34 bytes each)
+        2 +  // system_sizes
+        (4 + num_legs * 8) +  // system_multipliers
         1 +  // settled
         1 +  // claimed
         8 +  // claim_deadline
         (1 + 32) + // bounty_claimer (Option<Pubkey>)
-        1;   // bump
+        8 +  // reserved_liability
+        1    // bump
+    }
 
     pub fn get_predictions(&self) -> &[Prediction] {
         &self.predictions[0..self.num_predictions as usize]
     }
+
+    /// Rounds other than the bet's own that its legs reference, in leg order
+    pub fn other_rounds(&self) -> Vec<u64> {
+        let mut rounds: Vec<u64> = Vec::new();
+        for prediction in self.get_predictions() {
+            if prediction.round_id != self.round_id && !rounds.contains(&prediction.round_id) {
+                rounds.push(prediction.round_id);
+            }
+        }
+        rounds
+    }
+
+    /// Scale the stake and every leg's allocation down by `fraction_bps`
+    ///
+    /// Returns the amount pulled from each leg's pool, in prediction order
+    pub fn reduce_stake(&mut self, fraction_bps: u64) -> Result<Vec<u64>> {
+        require!(
+            fraction_bps > 0 && fraction_bps < BPS_DENOMINATOR,
+            SportsbookError::InvalidCashOutFraction
+        );
+        let keep_bps = BPS_DENOMINATOR - fraction_bps;
+        let scale = |value: u64| -> Result<u64> {
+            Ok((value as u128)
+                .checked_mul(keep_bps as u128)
+                .ok_or(SportsbookError::CalculationOverflow)?
+                .checked_div(BPS_DENOMINATOR as u128)
+                .ok_or(SportsbookError::CalculationOverflow)? as u64)
+        };
+
+        self.amount = scale(self.amount)?;
+        self.amount_after_fee = scale(self.amount_after_fee)?;
+
+        let mut removed = Vec::with_capacity(self.num_predictions as usize);
+        let mut allocated = 0u64;
+        for prediction in self.predictions[0..self.num_predictions as usize].iter_mut() {
+            let kept = scale(prediction.amount_in_pool)?;
+            removed.push(prediction.amount_in_pool - kept);
+            prediction.amount_in_pool = kept;
+            allocated += kept;
+        }
+        self.allocated_amount = allocated;
+
+        Ok(removed)
+    }
 }
@@ -15,6 +15,18 @@ pub struct BettingPool {
     /// Liquidity pool PDA
     pub liquidity_pool: Pubkey,
 
+    /// Odds oracle allowed to price rounds before seeding (default = none)
+    pub odds_oracle: Pubkey,
+
+    /// Rules on disputed results: takes over a round's proposed results when they are challenged
+    pub results_resolver: Pubkey,
+
+    /// Seconds proposed results stay open to dispute before they can settle a round
+    pub challenge_period: i64,
+
+    /// Tokens a disputer bonds (returned if the resolver corrects the results)
+    pub dispute_bond: u64,
+
     /// Protocol fee in basis points (e.g., 500 = 5%)
     pub protocol_fee_bps: u16,
 
@@ -24,9 +36,36 @@ pub struct BettingPool {
     /// Season pool share in basis points (e.g., 200 = 2%)
     pub season_pool_share_bps: u16,
 
-    /// Total season reward pool accumulated
+    /// House margin taken off cash-out quotes in basis points (e.g., 500 = 5%)
+    pub cash_out_margin_bps: u16,
+
+    /// How far market prices may move from their seeded price on exposure, in basis points
+    /// (e.g., 2000 = ±20%; 0 = fixed odds)
+    pub reprice_band_bps: u16,
+
+    /// Decimals of the betting token mint (token amount constants are scaled to these)
+    pub token_decimals: u8,
+
+    /// Share of available LP liquidity seeded into each round, in basis points (e.g., 1000 = 10%)
+    pub seed_fraction_bps: u16,
+
+    /// Total season reward pool accumulated (mirrors the season vault balance)
     pub season_reward_pool: u64,
 
+    /// Part of the season reward pool still owed to ended seasons' predictors
+    /// (a new season's snapshot only covers the rest)
+    pub season_rewards_committed: u64,
+
+    /// Season vault PDA token account holding the season reward pool
+    pub season_vault: Pubkey,
+
+    /// Max payout reserved for open cross-round bets (kept out of new bets' solvency checks)
+    pub cross_round_liability: u64,
+
+    /// Payout reserved for season outright markets (kept out of every solvency check):
+    /// each open season's largest team liability, each ended season's unpaid winners
+    pub outright_reserve: u64,
+
     /// Next bet ID counter
     pub next_bet_id: u64,
 
@@ -50,6 +89,19 @@ pub struct BettingPool {
     /// Winning team index for current season (0-9, set when season ends)
     pub season_winning_team: u8,
 
+    /// First round ID of the current season
+    pub season_start_round_id: u64,
+
+    /// Season predictions close once this many rounds have been initialized
+    pub prediction_cutoff_rounds: u64,
+
+    /// Total prediction weight backing each team this season
+    pub season_team_weights: [u64; 10],
+
+    /// Season reward pool snapshot taken when the season ends
+    /// Reward shares are computed from this so claim order doesn't matter
+    pub season_reward_snapshot: u64,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -60,10 +112,22 @@ impl BettingPool {
         32 + // token_mint
         32 + // protocol_treasury
         32 + // liquidity_pool
+        32 + // odds_oracle
+        32 + // results_resolver
+        8 +  // challenge_period
+        8 +  // dispute_bond
         2 +  // protocol_fee_bps
         2 +  // winner_share_bps
         2 +  // season_pool_share_bps
+        2 +  // cash_out_margin_bps
+        2 +  // reprice_band_bps
+        1 +  // token_decimals
+        2 +  // seed_fraction_bps
         8 +  // season_reward_pool
+        8 +  // season_rewards_committed
+        32 + // season_vault
+        8 +  // cross_round_liability
+        8 +  // outright_reserve
         8 +  // next_bet_id
         8 +  // next_round_id
         (32 * 10) + // team_token_mints (10 teams)
@@ -71,5 +135,9 @@ impl BettingPool {
         8 +  // current_season_id
         1 +  // season_ended
         1 +  // season_winning_team
+        8 +  // season_start_round_id
+        8 +  // prediction_cutoff_rounds
+        (8 * 10) + // season_team_weights (10 teams)
+        8 +  // season_reward_snapshot
         1;   // bump
 }
@@ -0,0 +1,275 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_MARKET_SELECTIONS, ODDS_SCALE};
+use crate::errors::SportsbookError;
+use crate::utils::{resolve_selection, calculate_repriced_odds};
+use super::{LegResult, MatchScore};
+
+/// A single selection within a market
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct MarketSelection {
+    /// Total tokens allocated to this selection
+    pub pool: u64,
+
+    /// Price offered to the next bet (scaled by 1e9); each bet locks the price it took.
+    /// Parimutuel markets overwrite this with the final pool odds on resolve
+    pub odds: u64,
+
+    /// Price the selection was seeded at when the market was created
+    pub seed_odds: u64,
+
+    /// Owed if this selection wins outright (each stake × the odds it locked)
+    pub liability: u64,
+
+    /// Book's net loss from bets if this selection wins (payouts owed minus stakes taken)
+    pub exposure: i64,
+
+    /// Settlement result (Pending until the market is resolved)
+    pub result: LegResult,
+}
+
+/// A betting market on one match of a round (1X2, over/under, correct score, ...)
+///
+/// Selections are indexed by outcome - 1 using the outcome encoding of the market type
+#[account]
+pub struct Market {
+    /// Betting pool this market belongs to
+    pub betting_pool: Pubkey,
+
+    /// Round ID
+    pub round_id: u64,
+
+    /// Match index within the round (below its `num_matches`)
+    pub match_index: u8,
+
+    /// Market type (see MARKET_* constants)
+    pub market_type: u8,
+
+    /// Number of selections offered
+    pub num_selections: u8,
+
+    /// Per-selection pools, prices, liability, exposure and result
+    pub selections: [MarketSelection; MAX_MARKET_SELECTIONS],
+
+    /// Sum of all selection pools
+    pub total_pool: u64,
+
+    /// Cap on any single selection's liability (0 = uncapped)
+    pub max_liability: u64,
+
+    /// How far prices may move from their seeded price, in basis points (0 = fixed odds)
+    pub reprice_band_bps: u16,
+
+    /// Seed liquidity of this market's match; net exposure of this size moves a price the full band
+    pub seed_liquidity: u64,
+
+    /// Parimutuel market: winners split the pool instead of taking locked odds
+    pub parimutuel: bool,
+
+    /// Has the market been resolved against the match score?
+    pub resolved: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Market {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // betting_pool
+        8 +  // round_id
+        1 +  // match_index
+        1 +  // market_type
+        1 +  // num_selections
+        (MAX_MARKET_SELECTIONS * 41) + // selections (41 bytes each)
+        8 +  // total_pool
+        8 +  // max_liability
+        2 +  // reprice_band_bps
+        8 +  // seed_liquidity
+        1 +  // parimutuel
+        1 +  // resolved
+        1;   // bump
+
+    /// Selection for an outcome (1-based), if the market offers it
+    pub fn selection(&self, outcome: u8) -> Option<&MarketSelection> {
+        if outcome == 0 || outcome > self.num_selections {
+            return None;
+        }
+        Some(&self.selections[(outcome - 1) as usize])
+    }
+
+    /// Current price for an outcome (0 if the market doesn't offer it)
+    pub fn odds(&self, outcome: u8) -> u64 {
+        self.selection(outcome).map_or(0, |selection| selection.odds)
+    }
+
+    /// Seed a selection with the protocol's own liquidity at its seeded price
+    ///
+    /// Seed stakes back the book rather than bet against it, so they don't count as exposure
+    pub fn seed_stake(&mut self, outcome: u8, amount: u64) -> Result<()> {
+        let odds = self.odds(outcome);
+        self.apply_stake(outcome, amount as i128, odds)
+    }
+
+    /// Add a leg's allocation at the odds it locked, enforcing the market's
+    /// liability cap, then reprice the market from the new exposure
+    pub fn add_stake(&mut self, outcome: u8, amount: u64, odds: u64) -> Result<()> {
+        self.apply_stake(outcome, amount as i128, odds)?;
+        self.apply_exposure(outcome, amount as i128, odds)?;
+
+        let liability = self.selections[(outcome - 1) as usize].liability;
+        require!(
+            self.max_liability == 0 || liability <= self.max_liability,
+            SportsbookError::MarketLiabilityExceeded
+        );
+
+        self.reprice();
+        Ok(())
+    }
+
+    /// Pull a leg's allocation back out at the odds it locked (cash-out, bet edits)
+    pub fn remove_stake(&mut self, outcome: u8, amount: u64, odds: u64) -> Result<()> {
+        self.apply_stake(outcome, -(amount as i128), odds)?;
+        self.apply_exposure(outcome, -(amount as i128), odds)?;
+
+        self.reprice();
+        Ok(())
+    }
+
+    /// Move `amount` (signed) in or out of a selection's pool and liability
+    fn apply_stake(&mut self, outcome: u8, amount: i128, odds: u64) -> Result<()> {
+        require!(
+            outcome > 0 && outcome <= self.num_selections,
+            SportsbookError::InvalidOutcome
+        );
+
+        let payout = amount
+            .checked_mul(odds as i128)
+            .ok_or(SportsbookError::CalculationOverflow)?
+            / ODDS_SCALE as i128;
+        let shift = |value: u64, delta: i128| -> Result<u64> {
+            u64::try_from(value as i128 + delta).map_err(|_| error!(SportsbookError::CalculationOverflow))
+        };
+
+        let selection = &mut self.selections[(outcome - 1) as usize];
+        selection.pool = shift(selection.pool, amount)?;
+        selection.liability = shift(selection.liability, payout)?;
+        self.total_pool = shift(self.total_pool, amount)?;
+
+        Ok(())
+    }
+
+    /// Book exposure for a stake on `outcome`: it owes the payout if the outcome
+    /// wins, and keeps the stake whichever selection wins
+    fn apply_exposure(&mut self, outcome: u8, amount: i128, odds: u64) -> Result<()> {
+        let payout = amount
+            .checked_mul(odds as i128)
+            .ok_or(SportsbookError::CalculationOverflow)?
+            / ODDS_SCALE as i128;
+
+        for (i, selection) in self.selections[..self.num_selections as usize].iter_mut().enumerate() {
+            let mut delta = -amount;
+            if i + 1 == outcome as usize {
+                delta += payout;
+            }
+            selection.exposure = i64::try_from(selection.exposure as i128 + delta)
+                .map_err(|_| error!(SportsbookError::CalculationOverflow))?;
+        }
+
+        Ok(())
+    }
+
+    /// Move every selection's price from its seeded price by the book's exposure on it
+    fn reprice(&mut self) {
+        let band_bps = self.reprice_band_bps;
+        let exposure_scale = self.seed_liquidity;
+        for selection in self.selections[..self.num_selections as usize].iter_mut() {
+            selection.odds = calculate_repriced_odds(selection.seed_odds, selection.exposure, band_bps, exposure_scale);
+        }
+    }
+
+    /// Settle every selection against the match's final score
+    pub fn resolve(&mut self, score: &MatchScore) {
+        let market_type = self.market_type;
+        for (i, selection) in self.selections[..self.num_selections as usize].iter_mut().enumerate() {
+            selection.result = resolve_selection(market_type, i as u8 + 1, score);
+        }
+
+        if self.parimutuel {
+            let odds = self.parimutuel_odds();
+            for selection in self.selections[..self.num_selections as usize].iter_mut() {
+                selection.odds = odds;
+            }
+        }
+
+        self.resolved = true;
+    }
+
+    /// Odds paid to winning stakes under parimutuel settlement
+    ///
+    /// Void (and the void half of quarter-line) stakes are refunded first; the rest of
+    /// the pool is split over winning stakes, half-wins counting as half a stake
+    fn parimutuel_odds(&self) -> u64 {
+        let mut refunded = 0u128;
+        let mut winning_weight = 0u128;
+        for selection in self.selections[..self.num_selections as usize].iter() {
+            let pool = selection.pool as u128;
+            match selection.result {
+                LegResult::Won => winning_weight += pool,
+                LegResult::HalfWin => {
+                    winning_weight += pool / 2;
+                    refunded += pool / 2;
+                }
+                LegResult::Void => refunded += pool,
+                LegResult::HalfLose => refunded += pool / 2,
+                LegResult::Lost | LegResult::Pending => {}
+            }
+        }
+
+        // Nobody to pay: the price is irrelevant, any non-zero odds will do
+        if winning_weight == 0 {
+            return ODDS_SCALE;
+        }
+
+        ((self.total_pool as u128).saturating_sub(refunded) * ODDS_SCALE as u128 / winning_weight)
+            .min(u64::MAX as u128) as u64
+    }
+
+    /// Odds a resolved selection pays at when they come from the market rather than
+    /// the bet's own snapshot (parimutuel final pool odds)
+    pub fn settlement_odds(&self, outcome: u8) -> Option<u64> {
+        if self.parimutuel {
+            self.selection(outcome).map(|selection| selection.odds)
+        } else {
+            None
+        }
+    }
+
+    /// Total owed across all selections at their settled odds (after resolve)
+    ///
+    /// Stakes lock different prices as the market moves, so each selection settles
+    /// at its pool-weighted average locked odds (liability / pool); parimutuel
+    /// selections all settle at the final pool odds
+    pub fn total_owed(&self) -> Result<u64> {
+        let mut owed = 0u64;
+        for selection in self.selections[..self.num_selections as usize].iter() {
+            if selection.pool == 0 {
+                continue;
+            }
+            let odds = if self.parimutuel {
+                selection.odds
+            } else {
+                (selection.liability as u128)
+                    .checked_mul(ODDS_SCALE as u128)
+                    .ok_or(SportsbookError::CalculationOverflow)?
+                    .checked_div(selection.pool as u128)
+                    .ok_or(SportsbookError::CalculationOverflow)? as u64
+            };
+            let settled_odds = selection.result.settled_odds(odds);
+            owed += (selection.pool as u128)
+                .checked_mul(settled_odds as u128)
+                .ok_or(SportsbookError::CalculationOverflow)?
+                .checked_div(ODDS_SCALE as u128)
+                .ok_or(SportsbookError::CalculationOverflow)? as u64;
+        }
+        Ok(owed)
+    }
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
+use crate::constants::{
+    OVER_UNDER_LINE_GOALS, OUTCOME_OVER, OUTCOME_UNDER, CORRECT_SCORE_MAX_GOALS,
+    CORRECT_SCORE_ANY_OTHER, OUTCOME_HOME_OR_DRAW, OUTCOME_AWAY_OR_DRAW, OUTCOME_HOME_OR_AWAY,
+    OUTCOME_BTTS_YES, OUTCOME_BTTS_NO, ASIAN_HANDICAP_LINES, NUM_ASIAN_HANDICAP_OUTCOMES, ODDS_SCALE,
+};
 
-/// Pool for a single match with betting on three outcomes
+/// Seed liquidity for a match's 1X2 book
+///
+/// Filled at seeding time and carried into the match result market when it is created
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct MatchPool {
     /// Total tokens bet on HOME_WIN (outcome 1)
@@ -82,6 +89,24 @@ impl LockedOdds {
     }
 }
 
+/// 1X2 prices for a match submitted by the odds oracle (scaled by 1e9)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct MatchOdds {
+    pub home_odds: u64,
+    pub away_odds: u64,
+    pub draw_odds: u64,
+}
+
+/// A real-world fixture registered on a round before it opens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct Fixture {
+    /// Data provider's fixture ID (bound into every result attestation)
+    pub fixture_id: u64,
+
+    /// Kickoff timestamp; betting on the match closes here
+    pub kickoff_time: i64,
+}
+
 /// Match outcome enumeration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum MatchOutcome {
@@ -96,3 +121,167 @@ impl Default for MatchOutcome {
         MatchOutcome::Pending
     }
 }
+
+impl MatchOutcome {
+    /// Does a double chance selection (1=1X, 2=X2, 3=12) cover this outcome?
+    pub fn covered_by_double_chance(&self, selection: u8) -> bool {
+        matches!(
+            (selection, self),
+            (OUTCOME_HOME_OR_DRAW, MatchOutcome::HomeWin | MatchOutcome::Draw)
+                | (OUTCOME_AWAY_OR_DRAW, MatchOutcome::AwayWin | MatchOutcome::Draw)
+                | (OUTCOME_HOME_OR_AWAY, MatchOutcome::HomeWin | MatchOutcome::AwayWin)
+        )
+    }
+}
+
+/// Result of a single prediction leg once its match is settled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum LegResult {
+    /// Market not resolved yet
+    #[default]
+    Pending,
+    Won,
+    Lost,
+    /// Stake returned (e.g. draw no bet on a draw), leg pays 1.0x
+    Void,
+    /// Quarter line: half the stake wins, half is returned
+    HalfWin,
+    /// Quarter line: half the stake is returned, half loses
+    HalfLose,
+}
+
+impl LegResult {
+    /// Effective odds the leg settles at, given its locked odds (scaled by 1e9)
+    pub fn settled_odds(&self, odds: u64) -> u64 {
+        match self {
+            LegResult::Won => odds,
+            LegResult::HalfWin => (odds + ODDS_SCALE) / 2,
+            LegResult::Void => ODDS_SCALE,
+            LegResult::HalfLose => ODDS_SCALE / 2,
+            LegResult::Lost | LegResult::Pending => 0,
+        }
+    }
+
+    /// Combine the two halves of a quarter-line stake
+    fn combine_halves(first: LegResult, second: LegResult) -> LegResult {
+        match (first, second) {
+            (a, b) if a == b => a,
+            (LegResult::Won, LegResult::Void) | (LegResult::Void, LegResult::Won) => LegResult::HalfWin,
+            (LegResult::Lost, LegResult::Void) | (LegResult::Void, LegResult::Lost) => LegResult::HalfLose,
+            // Neighbouring half-goal lines can't split win/lose on the same score
+            _ => LegResult::Lost,
+        }
+    }
+}
+
+/// Settle a whole or half-goal handicap line (in quarter goals) for one side
+fn handicap_line_result(goal_difference: i16, handicap_quarters: i16) -> LegResult {
+    let adjusted = goal_difference * 4 + handicap_quarters;
+    if adjusted > 0 {
+        LegResult::Won
+    } else if adjusted == 0 {
+        LegResult::Void
+    } else {
+        LegResult::Lost
+    }
+}
+
+/// Decode an Asian handicap outcome into (handicap for the backed side in quarter goals, is_home)
+pub fn decode_asian_handicap_outcome(outcome: u8) -> Option<(i8, bool)> {
+    if outcome == 0 || outcome as usize > NUM_ASIAN_HANDICAP_OUTCOMES {
+        return None;
+    }
+
+    let line = ASIAN_HANDICAP_LINES[((outcome - 1) / 2) as usize];
+    let side = (outcome - 1) % 2;
+    let is_home = side == 0;
+
+    Some(if is_home { (line, true) } else { (-line, false) })
+}
+
+/// Final scoreline of a match (generated from VRF randomness)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MatchScore {
+    /// Goals scored by the home team
+    pub home_goals: u8,
+
+    /// Goals scored by the away team
+    pub away_goals: u8,
+}
+
+impl MatchScore {
+    /// 1X2 outcome implied by the scoreline
+    pub fn outcome(&self) -> MatchOutcome {
+        if self.home_goals > self.away_goals {
+            MatchOutcome::HomeWin
+        } else if self.away_goals > self.home_goals {
+            MatchOutcome::AwayWin
+        } else {
+            MatchOutcome::Draw
+        }
+    }
+
+    pub fn total_goals(&self) -> u16 {
+        self.home_goals as u16 + self.away_goals as u16
+    }
+
+    /// Winning over/under outcome (1=OVER, 2=UNDER) for the round's goal line
+    pub fn totals_outcome(&self) -> u8 {
+        if self.total_goals() > OVER_UNDER_LINE_GOALS as u16 {
+            OUTCOME_OVER
+        } else {
+            OUTCOME_UNDER
+        }
+    }
+
+    /// Winning correct score outcome (1-16 grid, 17 = any other)
+    pub fn correct_score_outcome(&self) -> u8 {
+        correct_score_outcome(self.home_goals, self.away_goals)
+    }
+
+    /// Winning both-teams-to-score outcome (1=YES, 2=NO)
+    pub fn btts_outcome(&self) -> u8 {
+        if self.home_goals > 0 && self.away_goals > 0 {
+            OUTCOME_BTTS_YES
+        } else {
+            OUTCOME_BTTS_NO
+        }
+    }
+
+    /// Settle an Asian handicap selection against this scoreline
+    ///
+    /// Quarter lines are split into the two neighbouring lines, half the stake on each
+    pub fn asian_handicap_result(&self, outcome: u8) -> LegResult {
+        let (handicap, is_home) = match decode_asian_handicap_outcome(outcome) {
+            Some(decoded) => decoded,
+            None => return LegResult::Lost,
+        };
+
+        let goal_difference = if is_home {
+            self.home_goals as i16 - self.away_goals as i16
+        } else {
+            self.away_goals as i16 - self.home_goals as i16
+        };
+
+        let handicap = handicap as i16;
+        if handicap % 2 == 0 {
+            return handicap_line_result(goal_difference, handicap);
+        }
+
+        LegResult::combine_halves(
+            handicap_line_result(goal_difference, handicap - 1),
+            handicap_line_result(goal_difference, handicap + 1),
+        )
+    }
+}
+
+/// Encode a scoreline as a correct score outcome
+///
+/// Grid scores (0-3 goals per side) map to 1 + home × 4 + away; anything else is "any other"
+pub fn correct_score_outcome(home_goals: u8, away_goals: u8) -> u8 {
+    if home_goals > CORRECT_SCORE_MAX_GOALS || away_goals > CORRECT_SCORE_MAX_GOALS {
+        return CORRECT_SCORE_ANY_OTHER;
+    }
+
+    1 + home_goals * (CORRECT_SCORE_MAX_GOALS + 1) + away_goals
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+/// Compression curve mapping raw parimutuel odds into the offered odds band
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OddsCurve {
+    /// Raw odds at or below this map to `min_odds` (scaled by 1e9)
+    pub raw_odds_min: u64,
+
+    /// Raw odds at or above this map to `max_odds` (scaled by 1e9)
+    pub raw_odds_max: u64,
+
+    /// Shortest compressed odds (scaled by 1e9)
+    pub min_odds: u64,
+
+    /// Longest compressed odds (scaled by 1e9)
+    pub max_odds: u64,
+
+    /// Curve type (see ODDS_CURVE_* constants)
+    pub curve: u8,
+
+    /// Logistic steepness or power exponent (scaled by 1e9, unused for linear)
+    pub curve_param: u64,
+}
+
+impl Default for OddsCurve {
+    /// The original hard-coded 1.8x-5.5x → 1.2x-2.2x linear compression
+    fn default() -> Self {
+        Self {
+            raw_odds_min: RAW_ODDS_MIN,
+            raw_odds_max: RAW_ODDS_MAX,
+            min_odds: MIN_COMPRESSED_ODDS,
+            max_odds: MAX_COMPRESSED_ODDS,
+            curve: ODDS_CURVE_LINEAR,
+            curve_param: 0,
+        }
+    }
+}
+
+impl OddsCurve {
+    pub fn is_valid(&self) -> bool {
+        let param_ok = match self.curve {
+            ODDS_CURVE_LINEAR => true,
+            ODDS_CURVE_LOGISTIC => self.curve_param > 0 && self.curve_param <= MAX_LOGISTIC_STEEPNESS,
+            ODDS_CURVE_POWER => (MIN_POWER_EXPONENT..=MAX_POWER_EXPONENT).contains(&self.curve_param),
+            _ => false,
+        };
+
+        param_ok
+            && self.raw_odds_min < self.raw_odds_max
+            && ODDS_SCALE < self.min_odds
+            && self.min_odds < self.max_odds
+    }
+}
+
+/// House pricing profile: how seeded odds are compressed and the margin on each market
+///
+/// Tuned by the trading team without a redeploy; seeding and market creation apply it
+#[account]
+pub struct OddsProfile {
+    /// Betting pool this profile prices for
+    pub betting_pool: Pubkey,
+
+    /// Compression curve for seeded 1X2 odds
+    pub curve: OddsCurve,
+
+    /// Target book overround per market type, in basis points (index = market type)
+    /// For the 1X2 market 0 leaves the compressed odds as they are
+    pub overround_bps: [u16; NUM_MARKET_TYPES],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl OddsProfile {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // betting_pool
+        (8 * 4 + 1 + 8) + // curve (bounds, type, param)
+        (2 * NUM_MARKET_TYPES) + // overround_bps
+        1;   // bump
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_ORACLE_SIGNERS;
+
+/// Keys that attest real-world fixture results, and how many must agree
+#[account]
+pub struct OracleSet {
+    /// Betting pool this oracle set serves
+    pub betting_pool: Pubkey,
+
+    /// Oracle keys (first `num_signers` are in use)
+    pub signers: [Pubkey; MAX_ORACLE_SIGNERS],
+
+    /// Number of oracle keys
+    pub num_signers: u8,
+
+    /// Distinct oracle signatures required on a result attestation (M of N)
+    pub threshold: u8,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl OracleSet {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // betting_pool
+        (32 * MAX_ORACLE_SIGNERS) + // signers
+        1 +  // num_signers
+        1 +  // threshold
+        1;   // bump
+
+    /// Oracle keys in use
+    pub fn get_signers(&self) -> &[Pubkey] {
+        &self.signers[..self.num_signers as usize]
+    }
+
+    /// How many distinct oracle keys are among `signers`
+    pub fn count_attestations(&self, signers: &[Pubkey]) -> usize {
+        self.get_signers()
+            .iter()
+            .filter(|oracle| signers.contains(oracle))
+            .count()
+    }
+}
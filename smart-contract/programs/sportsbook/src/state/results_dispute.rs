@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// A bonded challenge to a round's proposed results, awaiting the results resolver
+#[account]
+pub struct ResultsDispute {
+    /// Round whose results are disputed
+    pub round_id: u64,
+
+    /// Who raised the dispute (receives the account rent back)
+    pub disputer: Pubkey,
+
+    /// Token account the bond is refunded to if the dispute is upheld
+    pub disputer_token_account: Pubkey,
+
+    /// Bond posted, in pool token units
+    pub bond: u64,
+
+    /// When the dispute was raised
+    pub raised_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ResultsDispute {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // round_id
+        32 + // disputer
+        32 + // disputer_token_account
+        8 +  // bond
+        8 +  // raised_at
+        1;   // bump
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use super::{MatchPool, LockedOdds, MatchOutcome};
+use crate::constants::ROUND_FIXTURES;
+use crate::errors::SportsbookError;
+use super::{MatchPool, LockedOdds, MatchOutcome, MatchScore, Fixture};
 
-/// Accounting data for a single betting round (10 matches)
+/// Accounting data for a single betting round (`num_matches` matches, sized at initialization)
 #[account]
 pub struct RoundAccounting {
     /// Round ID
@@ -10,25 +12,34 @@ pub struct RoundAccounting {
     /// Betting pool this round belongs to
     pub betting_pool: Pubkey,
 
-    /// Match pools (10 matches per round)
-    pub match_pools: [MatchPool; 10],
+    /// Number of matches in the round (every per-match list has this length)
+    pub num_matches: u8,
 
-    /// Locked odds per match (fixed at seeding time)
-    pub locked_odds: [LockedOdds; 10],
+    /// Seeded 1X2 liquidity per match (betting happens in each match's markets)
+    pub match_pools: Vec<MatchPool>,
+
+    /// Locked 1X2 odds per match (fixed at seeding time, every market is priced from these)
+    pub locked_odds: Vec<LockedOdds>,
+
+    /// Book overround of each match's locked 1X2 odds, in basis points (recorded at seeding)
+    pub book_overround_bps: Vec<u16>,
 
     /// Match results (outcomes)
-    pub match_results: [MatchOutcome; 10],
+    pub match_results: Vec<MatchOutcome>,
+
+    /// Match scorelines (markets resolve against these)
+    pub match_scores: Vec<MatchScore>,
 
     /// Total bet volume in this round (including bonuses)
     pub total_bet_volume: u64,
 
-    /// Total winning pool amount
+    /// Total winning pool amount (accumulated as markets resolve)
     pub total_winning_pool: u64,
 
     /// Total losing pool amount
     pub total_losing_pool: u64,
 
-    /// Total reserved for winners
+    /// Total reserved for winners (accumulated as markets resolve)
     pub total_reserved_for_winners: u64,
 
     /// Total claimed so far
@@ -70,17 +81,112 @@ pub struct RoundAccounting {
     /// Has round been settled?
     pub settled: bool,
 
+    /// Pricing mode (see PRICING_* constants)
+    pub pricing_mode: u8,
+
+    /// Locked odds were submitted by the odds oracle (seeding derives the pools from them)
+    pub oracle_odds: bool,
+
+    /// Sequence of the last oracle price set applied (each submission must exceed it)
+    pub odds_sequence: u64,
+
+    /// Round kind (see ROUND_* constants)
+    pub round_kind: u8,
+
+    /// Real-world fixtures (fixture rounds only)
+    pub fixtures: Vec<Fixture>,
+
+    /// When the pending results were proposed (0 = none); they settle their matches after the challenge period
+    pub results_proposed_at: i64,
+
+    /// Matches in the pending proposal (bit i = match i)
+    pub proposed_matches: u32,
+
+    /// When each match's result became final (0 = unsettled); the round settles with its last match
+    pub match_settled_at: Vec<i64>,
+
+    /// Matches VRF randomness has been requested for (bit i = match i)
+    pub randomness_requested: u32,
+
+    /// Per-match betting cutoff (0 = none; fixture matches also close at kickoff)
+    pub betting_cutoffs: Vec<i64>,
+
+    /// Open cross-round bets with a leg in this round (revenue can't be finalized until they close)
+    pub open_cross_round_bets: u32,
+
+    /// Is a bonded dispute of the proposed results awaiting the resolver?
+    pub results_disputed: bool,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
 
 impl RoundAccounting {
-    pub const LEN: usize = 8 + // discriminator
+    /// Has betting on a match closed? (fixture rounds close each match at kickoff)
+    pub fn match_started(&self, match_index: usize, now: i64) -> bool {
+        self.round_kind == ROUND_FIXTURES && now >= self.fixtures[match_index].kickoff_time
+    }
+
+    /// Is a match's result final?
+    pub fn match_settled(&self, match_index: usize) -> bool {
+        self.match_settled_at[match_index] != 0
+    }
+
+    /// Is a match's result known or being drawn? (randomness requested, proposed or settled)
+    pub fn match_result_in(&self, match_index: usize) -> bool {
+        let bit = 1u32 << match_index;
+        self.randomness_requested & bit != 0
+            || self.proposed_matches & bit != 0
+            || self.match_settled(match_index)
+    }
+
+    /// Can a match still take bets? Each match closes on its own: at its cutoff,
+    /// at kickoff for fixtures, or once its result is in
+    pub fn match_open(&self, match_index: usize, now: i64) -> bool {
+        let cutoff = self.betting_cutoffs[match_index];
+        !self.match_result_in(match_index)
+            && !self.match_started(match_index, now)
+            && (cutoff == 0 || now < cutoff)
+    }
+
+    /// Move a match's betting cutoff (0 = none); a closed match's cutoff stays where it is
+    pub fn set_betting_cutoff(&mut self, match_index: usize, cutoff: i64, now: i64) -> Result<()> {
+        if cutoff == self.betting_cutoffs[match_index] {
+            return Ok(());
+        }
+        require!(self.match_open(match_index, now), SportsbookError::BettingClosed);
+        require!(cutoff == 0 || cutoff > now, SportsbookError::InvalidBettingCutoff);
+        self.betting_cutoffs[match_index] = cutoff;
+        Ok(())
+    }
+
+    /// Make the pending proposal's results final, settling the round once every match has
+    pub fn settle_proposed_matches(&mut self, now: i64) {
+        for (i, settled_at) in self.match_settled_at.iter_mut().enumerate() {
+            if self.proposed_matches & (1 << i) != 0 {
+                *settled_at = now;
+            }
+        }
+        self.proposed_matches = 0;
+        self.results_proposed_at = 0;
+
+        if self.match_settled_at.iter().all(|&settled_at| settled_at != 0) {
+            self.settled = true;
+            self.round_end_time = now;
+        }
+    }
+
+    /// Account size for a round of `num_matches` matches
+    pub fn space(num_matches: usize) -> usize {
+        8 + // discriminator
         8 +  // round_id
         32 + // betting_pool
-        (10 * 32) + // match_pools (10 matches * 32 bytes each)
-        (10 * 25) + // locked_odds (10 matches * 25 bytes each)
-        (10 * 1) +  // match_results (10 outcomes)
+        1 +  // num_matches
+        (4 + num_matches * 32) + // match_pools (32 bytes each)
+        (4 + num_matches * 25) + // locked_odds (25 bytes each)
+        (4 + num_matches * 2) +  // book_overround_bps
+        (4 + num_matches) +      // match_results (1 byte each)
+        (4 + num_matches * 2) +  // match_scores (2 bytes each)
         8 +  // total_bet_volume
         8 +  // total_winning_pool
         8 +  // total_losing_pool
@@ -98,5 +204,160 @@ impl RoundAccounting {
         8 +  // round_start_time
         8 +  // round_end_time
         1 +  // settled
-        1;   // bump
+        1 +  // pricing_mode
+        1 +  // oracle_odds
+        8 +  // odds_sequence
+        1 +  // round_kind
+        (4 + num_matches * 16) + // fixtures (16 bytes each)
+        8 +  // results_proposed_at
+        4 +  // proposed_matches
+        (4 + num_matches * 8) +  // match_settled_at
+        4 +  // randomness_requested
+        (4 + num_matches * 8) +  // betting_cutoffs
+        4 +  // open_cross_round_bets
+        1 +  // results_disputed
+        1    // bump
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A seeded simulated round of `num_matches` matches, nothing proposed or settled
+    pub(crate) fn make_round(num_matches: u8) -> RoundAccounting {
+        let n = num_matches as usize;
+        RoundAccounting {
+            round_id: 1,
+            betting_pool: Pubkey::default(),
+            num_matches,
+            match_pools: vec![MatchPool::default(); n],
+            locked_odds: vec![LockedOdds::default(); n],
+            book_overround_bps: vec![0; n],
+            match_results: vec![MatchOutcome::default(); n],
+            match_scores: vec![MatchScore::default(); n],
+            total_bet_volume: 0,
+            total_winning_pool: 0,
+            total_losing_pool: 0,
+            total_reserved_for_winners: 0,
+            total_claimed: 0,
+            total_paid_out: 0,
+            protocol_fee_collected: 0,
+            protocol_revenue_share: 0,
+            season_revenue_share: 0,
+            revenue_distributed: false,
+            protocol_seed_amount: 0,
+            seeded: true,
+            total_user_deposits: 0,
+            parlay_count: 0,
+            round_start_time: 0,
+            round_end_time: 0,
+            settled: false,
+            pricing_mode: 0,
+            oracle_odds: false,
+            odds_sequence: 0,
+            round_kind: crate::constants::ROUND_SIMULATED,
+            fixtures: vec![Fixture::default(); n],
+            results_proposed_at: 0,
+            proposed_matches: 0,
+            match_settled_at: vec![0; n],
+            randomness_requested: 0,
+            betting_cutoffs: vec![0; n],
+            open_cross_round_bets: 0,
+            results_disputed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_partial_proposal_settles_only_its_matches() {
+        let mut round = make_round(3);
+        round.results_proposed_at = 500;
+        round.proposed_matches = 0b101;
+
+        round.settle_proposed_matches(1_000);
+
+        assert!(round.match_settled(0));
+        assert!(!round.match_settled(1));
+        assert!(round.match_settled(2));
+        assert_eq!(round.proposed_matches, 0);
+        assert_eq!(round.results_proposed_at, 0);
+        assert!(!round.settled);
+        assert_eq!(round.round_end_time, 0);
+    }
+
+    #[test]
+    fn test_last_match_settles_round() {
+        let mut round = make_round(3);
+        round.proposed_matches = 0b011;
+        round.settle_proposed_matches(1_000);
+
+        round.proposed_matches = 0b100;
+        round.settle_proposed_matches(2_000);
+
+        assert!(round.settled);
+        assert_eq!(round.round_end_time, 2_000);
+        // Earlier matches keep their own settlement time
+        assert_eq!(round.match_settled_at, vec![1_000, 1_000, 2_000]);
+    }
+
+    #[test]
+    fn test_each_match_closes_at_its_own_cutoff() {
+        let mut round = make_round(3);
+        round.betting_cutoffs = vec![1_000, 0, 2_000];
+
+        assert!(round.match_open(0, 999));
+        assert!(!round.match_open(0, 1_000));
+        // No cutoff: open until its result is in
+        assert!(round.match_open(1, 5_000));
+        assert!(round.match_open(2, 1_500));
+        assert!(!round.match_open(2, 2_000));
+    }
+
+    #[test]
+    fn test_fixture_match_closes_at_kickoff() {
+        let mut round = make_round(2);
+        round.round_kind = ROUND_FIXTURES;
+        round.fixtures[0].kickoff_time = 1_000;
+        round.fixtures[1].kickoff_time = 3_000;
+
+        assert!(round.match_open(0, 999));
+        assert!(!round.match_open(0, 1_000));
+        assert!(round.match_open(1, 2_000));
+
+        // An earlier cutoff still applies
+        round.betting_cutoffs[1] = 1_500;
+        assert!(!round.match_open(1, 2_000));
+    }
+
+    #[test]
+    fn test_requested_and_proposed_matches_close() {
+        let mut round = make_round(4);
+        round.randomness_requested = 0b0001;
+        round.proposed_matches = 0b0100;
+        round.match_settled_at[3] = 500;
+
+        assert!(round.match_result_in(0) && !round.match_open(0, 0));
+        assert!(!round.match_result_in(1) && round.match_open(1, 0));
+        assert!(round.match_result_in(2) && !round.match_open(2, 0));
+        assert!(round.match_result_in(3) && !round.match_open(3, 0));
+    }
+
+    #[test]
+    fn test_closed_match_cutoff_cannot_move() {
+        let mut round = make_round(2);
+        round.betting_cutoffs = vec![1_000, 3_000];
+
+        // Open match: cutoff moves to a future time or is cleared, never into the past
+        assert!(round.set_betting_cutoff(1, 4_000, 2_000).is_ok());
+        assert!(round.set_betting_cutoff(1, 1_500, 2_000).is_err());
+        assert!(round.set_betting_cutoff(1, 0, 2_000).is_ok());
+        assert_eq!(round.betting_cutoffs[1], 0);
+
+        // Closed match: reopening it is refused, resubmitting its cutoff is a no-op
+        assert!(round.set_betting_cutoff(0, 5_000, 2_000).is_err());
+        assert!(round.set_betting_cutoff(0, 0, 2_000).is_err());
+        assert!(round.set_betting_cutoff(0, 1_000, 2_000).is_ok());
+        assert_eq!(round.betting_cutoffs[0], 1_000);
+    }
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_NFT_SYMBOL_LEN, MAX_NFT_BASE_URI_LEN};
+
+/// Metadata configuration for season prediction NFTs
+/// Created alongside the season NFT collection
+#[account]
+pub struct SeasonNftConfig {
+    /// Betting pool this config belongs to
+    pub betting_pool: Pubkey,
+
+    /// Collection mint every prediction NFT is verified into
+    pub collection_mint: Pubkey,
+
+    /// Symbol shown by wallets (max 10 chars)
+    pub symbol: String,
+
+    /// Base URI for off-chain JSON; attributes are served per
+    /// `{base_uri}/{season}/{team}/{timestamp}.json`
+    pub base_uri: String,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeasonNftConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // betting_pool
+        32 + // collection_mint
+        (4 + MAX_NFT_SYMBOL_LEN) + // symbol (String)
+        (4 + MAX_NFT_BASE_URI_LEN) + // base_uri (String)
+        1;   // bump
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+/// Staked outright market for a season (bet on the league winner)
+/// One per season, created lazily by the first stake
+#[account]
+pub struct SeasonOutright {
+    /// Betting pool this market belongs to
+    pub betting_pool: Pubkey,
+
+    /// Season ID this market is for
+    pub season_id: u64,
+
+    /// Total tokens staked on each team (after fee)
+    pub team_pools: [u64; 10],
+
+    /// Total owed if each team wins (sum of locked potential payouts)
+    pub team_liability: [u64; 10],
+
+    /// Total tokens staked across all teams
+    pub total_staked: u64,
+
+    /// Total paid out to winning positions
+    pub total_paid_out: u64,
+
+    /// Has the season ended? (winning_team is final)
+    pub settled: bool,
+
+    /// Team that won the season (set when the season ends; claims check against it)
+    pub winning_team: u8,
+
+    /// This market's share of the betting pool's outright reserve
+    pub reserved_liability: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeasonOutright {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // betting_pool
+        8 +  // season_id
+        (8 * 10) + // team_pools (10 teams)
+        (8 * 10) + // team_liability (10 teams)
+        8 +  // total_staked
+        8 +  // total_paid_out
+        1 +  // settled
+        1 +  // winning_team
+        8 +  // reserved_liability
+        1;   // bump
+
+    /// Payout that must stay reserved: the largest team liability while the season is
+    /// open, the winners' unpaid liability once it has ended
+    pub fn required_reserve(&self) -> u64 {
+        if self.settled {
+            self.team_liability[self.winning_team as usize].saturating_sub(self.total_paid_out)
+        } else {
+            self.team_liability.iter().copied().max().unwrap_or(0)
+        }
+    }
+
+    /// Bring this market's share of the pool's outright reserve in line with `required_reserve`
+    pub fn update_reserve(&mut self, outright_reserve: &mut u64) {
+        let required = self.required_reserve();
+        *outright_reserve = outright_reserve
+            .saturating_sub(self.reserved_liability)
+            .saturating_add(required);
+        self.reserved_liability = required;
+    }
+}
+
+/// User's staked position on one team in a season outright market
+/// Repeated stakes on the same team accumulate, each at its own locked odds
+#[account]
+pub struct OutrightPosition {
+    /// User's public key
+    pub user: Pubkey,
+
+    /// Season ID this position is for
+    pub season_id: u64,
+
+    /// Team backed to win the season (0-9)
+    pub team: u8,
+
+    /// Total staked on this team (after fee)
+    pub stake: u64,
+
+    /// Sum of stake × locked odds over every stake placed
+    pub potential_payout: u64,
+
+    /// Has the position been claimed?
+    pub claimed: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl OutrightPosition {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        8 +  // season_id
+        1 +  // team
+        8 +  // stake
+        8 +  // potential_payout
+        1 +  // claimed
+        1;   // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outright(team_liability: [u64; 10]) -> SeasonOutright {
+        SeasonOutright {
+            betting_pool: Pubkey::default(),
+            season_id: 1,
+            team_pools: [0; 10],
+            team_liability,
+            total_staked: 0,
+            total_paid_out: 0,
+            settled: false,
+            winning_team: 0,
+            reserved_liability: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_open_season_reserves_largest_team_liability() {
+        let mut liability = [0u64; 10];
+        liability[2] = 300;
+        liability[7] = 500;
+        let mut market = outright(liability);
+
+        // Another season's reservation is left untouched
+        let mut outright_reserve = 1_000;
+        market.update_reserve(&mut outright_reserve);
+        assert_eq!(outright_reserve, 1_500);
+
+        market.team_liability[2] = 800;
+        market.update_reserve(&mut outright_reserve);
+        assert_eq!(outright_reserve, 1_800);
+        assert_eq!(market.reserved_liability, 800);
+    }
+
+    #[test]
+    fn test_ended_season_reserves_unpaid_winners_only() {
+        let mut liability = [0u64; 10];
+        liability[2] = 300;
+        liability[7] = 500;
+        let mut market = outright(liability);
+        let mut outright_reserve = 0;
+        market.update_reserve(&mut outright_reserve);
+
+        market.settled = true;
+        market.winning_team = 2;
+        market.update_reserve(&mut outright_reserve);
+        assert_eq!(outright_reserve, 300);
+
+        market.total_paid_out = 120;
+        market.update_reserve(&mut outright_reserve);
+        assert_eq!(outright_reserve, 180);
+    }
+}
@@ -14,7 +14,8 @@ pub struct SeasonPrediction {
     pub predicted_team: u8,
 
     /// NFT mint address for this prediction
-    /// Minted when prediction is made as proof of participation
+    /// Minted when prediction is made as proof of participation.
+    /// Whoever holds this NFT owns the prediction (claims and redemption)
     pub nft_mint: Pubkey,
 
     /// Has user claimed season rewards?
@@ -23,6 +24,12 @@ pub struct SeasonPrediction {
     /// Timestamp of prediction
     pub predicted_at: i64,
 
+    /// Has the NFT been redeemed (upgraded if correct, burned if not)?
+    pub nft_redeemed: bool,
+
+    /// Reward weight (earlier predictions weigh more, scaled by PREDICTION_WEIGHT_SCALE)
+    pub weight: u64,
+
     /// Bump seed for PDA
     pub bump: u8,
 }
@@ -35,5 +42,41 @@ impl SeasonPrediction {
         32 + // nft_mint
         1 +  // claimed_reward
         8 +  // predicted_at
+        1 +  // nft_redeemed
+        8 +  // weight
+        1;   // bump
+}
+
+/// Snapshot of a season taken when it ends
+/// Season rewards and NFT redemptions are computed from it, so they stay
+/// claimable after later seasons start
+#[account]
+pub struct SeasonResult {
+    /// Season ID this result is for
+    pub season_id: u64,
+
+    /// Team that won the season
+    pub winning_team: u8,
+
+    /// Total prediction weight backing each team
+    pub team_weights: [u64; 10],
+
+    /// Season's own reward pool at season end (reward shares are computed from this)
+    pub reward_snapshot: u64,
+
+    /// Part of the snapshot its predictors can still claim
+    pub unclaimed_rewards: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SeasonResult {
+    pub const LEN: usize = 8 + // discriminator
+        8 +  // season_id
+        1 +  // winning_team
+        (8 * 10) + // team_weights (10 teams)
+        8 +  // reward_snapshot
+        8 +  // unclaimed_rewards
         1;   // bump
 }
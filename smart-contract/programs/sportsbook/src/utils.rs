@@ -1,7 +1,15 @@
 pub mod odds;
 pub mod seeding;
 pub mod parlay;
+pub mod season;
+pub mod scoring;
+pub mod markets;
+pub mod ed25519;
 
 pub use odds::*;
 pub use seeding::*;
 pub use parlay::*;
+pub use season::*;
+pub use scoring::*;
+pub use markets::*;
+pub use ed25519::*;
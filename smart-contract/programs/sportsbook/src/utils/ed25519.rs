@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use solana_program::ed25519_program;
+use solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::errors::SportsbookError;
+
+/// Ed25519 program instruction layout: [num_signatures: u8, padding: u8, offsets...]
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Seven u16 offsets per signature
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+
+/// Instruction index meaning "data lives in the ed25519 instruction itself"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// (signer, message) pairs carried by an ed25519 program instruction
+///
+/// Only self-contained instructions are accepted: every signature, key and message
+/// must live in the ed25519 instruction's own data
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    let num_signatures = *data.first().ok_or(SportsbookError::InvalidOracleSignature)? as usize;
+
+    let mut signatures = Vec::with_capacity(num_signatures);
+    for i in 0..num_signatures {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_LEN;
+        let offsets = data
+            .get(start..start + SIGNATURE_OFFSETS_LEN)
+            .ok_or(SportsbookError::InvalidOracleSignature)?;
+        let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        // signature_instruction_index, public_key_instruction_index, message_instruction_index
+        require!(
+            read(2) == CURRENT_INSTRUCTION && read(6) == CURRENT_INSTRUCTION && read(12) == CURRENT_INSTRUCTION,
+            SportsbookError::InvalidOracleSignature
+        );
+
+        let key_offset = read(4) as usize;
+        let message_offset = read(8) as usize;
+        let message_len = read(10) as usize;
+
+        let key = data
+            .get(key_offset..key_offset + 32)
+            .ok_or(SportsbookError::InvalidOracleSignature)?;
+        let message = data
+            .get(message_offset..message_offset + message_len)
+            .ok_or(SportsbookError::InvalidOracleSignature)?;
+
+        signatures.push((Pubkey::try_from(key).unwrap(), message.to_vec()));
+    }
+
+    Ok(signatures)
+}
+
+/// Signers of `message` in the ed25519 instruction placed just before the current one
+///
+/// The ed25519 program has already checked the signatures by the time this instruction
+/// runs; this only reads back who signed what
+pub fn ed25519_signers(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, SportsbookError::InvalidOracleSignature);
+
+    let instruction = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID,
+        SportsbookError::InvalidOracleSignature
+    );
+
+    Ok(parse_ed25519_instruction(&instruction.data)?
+        .into_iter()
+        .filter(|(_, signed)| signed.as_slice() == message)
+        .map(|(signer, _)| signer)
+        .collect())
+}
+
+/// Require that `signer` signed `message` in the preceding ed25519 instruction
+pub fn verify_ed25519_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        ed25519_signers(instructions_sysvar, message)?.contains(signer),
+        SportsbookError::InvalidOracleSignature
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inline ed25519 instruction data in the layout the ed25519 program expects
+    fn ed25519_data(entries: &[(Pubkey, &[u8])]) -> Vec<u8> {
+        let header_len = SIGNATURE_OFFSETS_START + entries.len() * SIGNATURE_OFFSETS_LEN;
+        let mut data = vec![entries.len() as u8, 0];
+        let mut body = Vec::new();
+
+        for (key, message) in entries {
+            let key_offset = header_len + body.len();
+            body.extend_from_slice(key.as_ref());
+            let signature_offset = header_len + body.len();
+            body.extend_from_slice(&[0u8; 64]);
+            let message_offset = header_len + body.len();
+            body.extend_from_slice(message);
+
+            for value in [
+                signature_offset as u16,
+                CURRENT_INSTRUCTION,
+                key_offset as u16,
+                CURRENT_INSTRUCTION,
+                message_offset as u16,
+                message.len() as u16,
+                CURRENT_INSTRUCTION,
+            ] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        data.extend_from_slice(&body);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_reads_every_signer() {
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = ed25519_data(&[(alice, b"odds"), (bob, b"other")]);
+
+        let parsed = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(parsed, vec![(alice, b"odds".to_vec()), (bob, b"other".to_vec())]);
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_rejects_external_data() {
+        let mut data = ed25519_data(&[(Pubkey::new_unique(), b"odds")]);
+
+        // Point the message at another instruction
+        data[SIGNATURE_OFFSETS_START + 12..SIGNATURE_OFFSETS_START + 14].copy_from_slice(&0u16.to_le_bytes());
+        assert!(parse_ed25519_instruction(&data).is_err());
+
+        // Truncated data
+        assert!(parse_ed25519_instruction(&[1, 0, 0]).is_err());
+        assert!(parse_ed25519_instruction(&[]).is_err());
+    }
+}
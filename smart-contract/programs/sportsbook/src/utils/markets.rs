@@ -0,0 +1,176 @@
+use crate::constants::*;
+use crate::state::{LockedOdds, MatchScore, MatchOutcome, LegResult};
+use crate::utils::odds::{calculate_double_chance_odds, calculate_draw_no_bet_odds};
+use crate::utils::seeding::scale_token_amount;
+use crate::utils::scoring::{
+    calculate_totals_odds, calculate_correct_score_odds, calculate_btts_odds,
+    calculate_asian_handicap_odds,
+};
+
+/// Number of selectable outcomes in a market (0 for unknown markets)
+///
+/// MATCH_RESULT: 1=HOME_WIN, 2=AWAY_WIN, 3=DRAW
+/// TOTAL_GOALS: 1=OVER, 2=UNDER
+/// CORRECT_SCORE: 1-16=grid score, 17=any other
+/// DOUBLE_CHANCE: 1=1X, 2=X2, 3=12
+/// DRAW_NO_BET: 1=HOME, 2=AWAY
+/// BOTH_TEAMS_TO_SCORE: 1=YES, 2=NO
+/// ASIAN_HANDICAP: 1 + line_index × 2 + side (side 0=HOME, 1=AWAY)
+pub fn market_outcome_count(market_type: u8) -> u8 {
+    match market_type {
+        MARKET_MATCH_RESULT => 3,
+        MARKET_TOTAL_GOALS => 2,
+        MARKET_CORRECT_SCORE => CORRECT_SCORE_ANY_OTHER,
+        MARKET_DOUBLE_CHANCE => 3,
+        MARKET_DRAW_NO_BET => 2,
+        MARKET_BOTH_TEAMS_TO_SCORE => 2,
+        MARKET_ASIAN_HANDICAP => NUM_ASIAN_HANDICAP_OUTCOMES as u8,
+        _ => 0,
+    }
+}
+
+/// Check an outcome is valid for its market type
+pub fn is_valid_outcome(market_type: u8, outcome: u8) -> bool {
+    (1..=market_outcome_count(market_type)).contains(&outcome)
+}
+
+/// Lock odds for every selection of a market from the match's locked 1X2 odds
+///
+/// `margin_bps` is taken off each fair price (the 1X2 market carries its seeded odds as-is).
+/// Index = outcome - 1; unused selections are left at 0
+pub fn price_market(market_type: u8, odds: &LockedOdds, margin_bps: u64) -> [u64; MAX_MARKET_SELECTIONS] {
+    let mut prices = [0u64; MAX_MARKET_SELECTIONS];
+
+    match market_type {
+        MARKET_MATCH_RESULT => {
+            prices[0] = odds.home_odds;
+            prices[1] = odds.away_odds;
+            prices[2] = odds.draw_odds;
+        }
+        MARKET_TOTAL_GOALS => {
+            let (over_odds, under_odds) = calculate_totals_odds(odds, margin_bps);
+            prices[(OUTCOME_OVER - 1) as usize] = over_odds;
+            prices[(OUTCOME_UNDER - 1) as usize] = under_odds;
+        }
+        MARKET_CORRECT_SCORE => {
+            prices[..NUM_CORRECT_SCORE_OUTCOMES].copy_from_slice(&calculate_correct_score_odds(odds, margin_bps));
+        }
+        MARKET_DOUBLE_CHANCE => {
+            for outcome in 1..=market_outcome_count(market_type) {
+                prices[(outcome - 1) as usize] = calculate_double_chance_odds(odds, outcome, margin_bps);
+            }
+        }
+        MARKET_DRAW_NO_BET => {
+            for outcome in 1..=market_outcome_count(market_type) {
+                prices[(outcome - 1) as usize] = calculate_draw_no_bet_odds(odds, outcome, margin_bps);
+            }
+        }
+        MARKET_BOTH_TEAMS_TO_SCORE => {
+            let (yes_odds, no_odds) = calculate_btts_odds(odds, margin_bps);
+            prices[(OUTCOME_BTTS_YES - 1) as usize] = yes_odds;
+            prices[(OUTCOME_BTTS_NO - 1) as usize] = no_odds;
+        }
+        MARKET_ASIAN_HANDICAP => {
+            prices[..NUM_ASIAN_HANDICAP_OUTCOMES].copy_from_slice(&calculate_asian_handicap_odds(odds, margin_bps));
+        }
+        _ => {}
+    }
+
+    prices
+}
+
+/// Default bookmaker margin for a market type, used when no odds profile overrides it
+pub fn default_market_margin_bps(market_type: u8) -> u64 {
+    match market_type {
+        MARKET_TOTAL_GOALS => OVER_UNDER_MARGIN_BPS,
+        MARKET_CORRECT_SCORE => CORRECT_SCORE_MARGIN_BPS,
+        MARKET_DOUBLE_CHANCE | MARKET_DRAW_NO_BET => DERIVED_MARKET_MARGIN_BPS,
+        MARKET_BOTH_TEAMS_TO_SCORE => BTTS_MARGIN_BPS,
+        MARKET_ASIAN_HANDICAP => ASIAN_HANDICAP_MARGIN_BPS,
+        _ => 0,
+    }
+}
+
+/// Cap on any single selection's liability for a market type (0 = uncapped),
+/// in units of a mint with `decimals`
+pub fn market_liability_cap(market_type: u8, decimals: u8) -> u64 {
+    match market_type {
+        MARKET_CORRECT_SCORE => scale_token_amount(MAX_CORRECT_SCORE_LIABILITY_PER_MATCH, decimals),
+        _ => 0,
+    }
+}
+
+/// Settle one selection of a market against a match's final score
+pub fn resolve_selection(market_type: u8, outcome: u8, score: &MatchScore) -> LegResult {
+    let match_result = score.outcome();
+
+    let won = match market_type {
+        MARKET_MATCH_RESULT => match_result as u8 == outcome,
+        MARKET_TOTAL_GOALS => score.totals_outcome() == outcome,
+        MARKET_CORRECT_SCORE => score.correct_score_outcome() == outcome,
+        MARKET_DOUBLE_CHANCE => match_result.covered_by_double_chance(outcome),
+        MARKET_DRAW_NO_BET => {
+            if match_result == MatchOutcome::Draw {
+                return LegResult::Void;
+            }
+            match_result as u8 == outcome
+        }
+        MARKET_BOTH_TEAMS_TO_SCORE => score.btts_outcome() == outcome,
+        MARKET_ASIAN_HANDICAP => return score.asian_handicap_result(outcome),
+        _ => false,
+    };
+
+    if won {
+        LegResult::Won
+    } else {
+        LegResult::Lost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_validation_per_market() {
+        assert!(is_valid_outcome(MARKET_MATCH_RESULT, 3));
+        assert!(!is_valid_outcome(MARKET_MATCH_RESULT, 0));
+        assert!(is_valid_outcome(MARKET_TOTAL_GOALS, OUTCOME_UNDER));
+        assert!(!is_valid_outcome(MARKET_TOTAL_GOALS, 3));
+        assert!(is_valid_outcome(MARKET_CORRECT_SCORE, CORRECT_SCORE_ANY_OTHER));
+        assert!(!is_valid_outcome(MARKET_CORRECT_SCORE, CORRECT_SCORE_ANY_OTHER + 1));
+        assert!(is_valid_outcome(MARKET_DOUBLE_CHANCE, OUTCOME_HOME_OR_AWAY));
+        assert!(!is_valid_outcome(MARKET_DRAW_NO_BET, 3));
+        assert!(!is_valid_outcome(7, 1));
+    }
+
+    #[test]
+    fn test_price_market_fills_every_selection() {
+        let odds = LockedOdds {
+            home_odds: 1_500_000_000,
+            away_odds: 2_000_000_000,
+            draw_odds: 1_800_000_000,
+            locked: true,
+        };
+
+        for market_type in MARKET_MATCH_RESULT..=MARKET_ASIAN_HANDICAP {
+            let prices = price_market(market_type, &odds, default_market_margin_bps(market_type));
+            let count = market_outcome_count(market_type) as usize;
+            assert!(prices[..count].iter().all(|&p| p > 0), "market {} has an unpriced selection", market_type);
+            assert!(prices[count..].iter().all(|&p| p == 0));
+        }
+    }
+
+    #[test]
+    fn test_resolve_selection_per_market() {
+        let score = MatchScore { home_goals: 1, away_goals: 1 };
+
+        assert_eq!(resolve_selection(MARKET_MATCH_RESULT, 3, &score), LegResult::Won);
+        assert_eq!(resolve_selection(MARKET_MATCH_RESULT, 1, &score), LegResult::Lost);
+        assert_eq!(resolve_selection(MARKET_TOTAL_GOALS, OUTCOME_UNDER, &score), LegResult::Won);
+        assert_eq!(resolve_selection(MARKET_DOUBLE_CHANCE, OUTCOME_AWAY_OR_DRAW, &score), LegResult::Won);
+        assert_eq!(resolve_selection(MARKET_DRAW_NO_BET, 1, &score), LegResult::Void);
+        assert_eq!(resolve_selection(MARKET_BOTH_TEAMS_TO_SCORE, OUTCOME_BTTS_YES, &score), LegResult::Won);
+        assert_eq!(resolve_selection(7, 1, &score), LegResult::Lost);
+    }
+}
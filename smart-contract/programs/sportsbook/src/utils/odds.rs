@@ -1,38 +1,151 @@
 use crate::constants::*;
-use crate::state::MatchPool;
+use crate::state::{LockedOdds, Market, OddsCurve};
+use crate::utils::scoring::{exp_neg, odds_from_probability};
 
 /// Compress raw parimutuel odds to target 1.2x - 2.2x range
 ///
 /// Maps raw odds (1.8x - 5.5x raw) to compressed range (1.2x - 2.2x)
 /// Strong favorites compress to near 1.2x; heavy underdogs to near 2.2x
 pub fn compress_odds(raw_odds: u64) -> u64 {
-    // Floor: 1.2x — even heavy favorites must pay something
-    if raw_odds < RAW_ODDS_MIN {
-        return MIN_COMPRESSED_ODDS;
+    compress_odds_on_curve(raw_odds, &OddsCurve::default())
+}
+
+/// Compress raw parimutuel odds into a profile's odds band along its curve
+pub fn compress_odds_on_curve(raw_odds: u64, curve: &OddsCurve) -> u64 {
+    // Floor: even heavy favorites must pay something
+    if raw_odds < curve.raw_odds_min {
+        return curve.min_odds;
     }
 
-    // Ceiling: 2.2x — cap huge underdogs
-    if raw_odds > RAW_ODDS_MAX {
-        return MAX_COMPRESSED_ODDS;
+    // Ceiling: cap huge underdogs
+    if raw_odds > curve.raw_odds_max {
+        return curve.max_odds;
     }
 
+    let excess = raw_odds.saturating_sub(curve.raw_odds_min);
+    let range = curve.raw_odds_max - curve.raw_odds_min;
+    let target_range = curve.max_odds - curve.min_odds;
+
     // Linear compression formula:
     // compressed = minOdds + (raw - minRaw) × (maxOdds - minOdds) / (maxRaw - minRaw)
-    // compressed = 1.2 + (raw - 1.8) × (2.2 - 1.2) / (5.5 - 1.8)
-    // compressed = 1.2 + (raw - 1.8) × 1.0 / 3.7
-    // compressed = 1.2 + (raw - 1.8) × 0.2703
+    // e.g. default band: compressed = 1.2 + (raw - 1.8) × 1.0 / 3.7
+    if curve.curve == ODDS_CURVE_LINEAR {
+        let scaled_excess = (excess as u128)
+            .checked_mul(target_range as u128)
+            .unwrap_or(0)
+            .checked_div(range as u128)
+            .unwrap_or(0) as u64;
+
+        return curve.min_odds + scaled_excess;
+    }
 
-    let excess = raw_odds.saturating_sub(RAW_ODDS_MIN);
-    let range = RAW_ODDS_MAX - RAW_ODDS_MIN; // 3.7e9
-    let target_range = MAX_COMPRESSED_ODDS - MIN_COMPRESSED_ODDS; // 0.7e9
+    // Shaped curves: position t in [0, 1] along the raw range, bent by the curve
+    let scale = ODDS_SCALE as u128;
+    let t = (excess as u128 * scale / range as u128) as u64;
+    let fraction = match curve.curve {
+        ODDS_CURVE_LOGISTIC => logistic_fraction(t, curve.curve_param),
+        ODDS_CURVE_POWER => power_fraction(t, curve.curve_param),
+        _ => t,
+    };
 
-    let scaled_excess = (excess as u128)
-        .checked_mul(target_range as u128)
-        .unwrap_or(0)
-        .checked_div(range as u128)
-        .unwrap_or(0) as u64;
+    curve.min_odds + (fraction as u128 * target_range as u128 / scale) as u64
+}
+
+/// ln 2 scaled by 1e9
+const LN_2: u128 = 693_147_181;
+
+/// Fixed-point -ln(x) for x in (0, 1] (x and result scaled by 1e9)
+///
+/// Halve the range into [0.5, 1], then ln x = 2·atanh((x-1)/(x+1)) by series
+fn neg_ln(x: u64) -> u64 {
+    let scale = ODDS_SCALE as i128;
+    let mut x = x as i128;
+    let mut halvings = 0i128;
+    while x < scale / 2 {
+        x *= 2;
+        halvings += 1;
+    }
 
-    MIN_COMPRESSED_ODDS + scaled_excess
+    // y in [-1/3, 0]: the odd-power series converges in a handful of terms
+    let y = (x - scale) * scale / (x + scale);
+    let y_squared = y * y / scale;
+    let mut power = y;
+    let mut ln = 0i128;
+    for k in 0..12i128 {
+        ln += power / (2 * k + 1);
+        power = power * y_squared / scale;
+    }
+
+    (halvings * LN_2 as i128 - 2 * ln).max(0) as u64
+}
+
+/// Logistic sigmoid of a signed fixed-point input (scaled by 1e9)
+fn sigmoid(z: i128) -> u128 {
+    let scale = ODDS_SCALE as u128;
+    let positive = scale * scale / (scale + exp_neg(z.unsigned_abs().min(u64::MAX as u128) as u64) as u128);
+    if z >= 0 { positive } else { scale - positive }
+}
+
+/// S-curve through (0, 0), (0.5, 0.5), (1, 1) with the given steepness
+fn logistic_fraction(t: u64, steepness: u64) -> u64 {
+    let scale = ODDS_SCALE as i128;
+    let k = steepness as i128;
+
+    let low = sigmoid(-k / 2);
+    let high = sigmoid(k / 2);
+    if high <= low {
+        return t;
+    }
+
+    let value = sigmoid(k * (t as i128 - scale / 2) / scale);
+    (value.saturating_sub(low) * scale as u128 / (high - low)) as u64
+}
+
+/// t^exponent via exp(-exponent × -ln t)
+fn power_fraction(t: u64, exponent: u64) -> u64 {
+    if t == 0 {
+        return 0;
+    }
+    let exponent_ln = neg_ln(t) as u128 * exponent as u128 / ODDS_SCALE as u128;
+    exp_neg(exponent_ln.min(u64::MAX as u128) as u64)
+}
+
+/// Margin (bps off fair odds) that gives a book the target overround
+///
+/// odds = fair / (1 + overround) = fair × (1 - overround / (1 + overround))
+pub fn overround_to_margin_bps(overround_bps: u16) -> u64 {
+    overround_bps as u64 * BPS_DENOMINATOR / (BPS_DENOMINATOR + overround_bps as u64)
+}
+
+/// Overround of a book (Σ implied probability - 1), in basis points
+pub fn book_overround_bps(odds: &[u64]) -> u64 {
+    let scale = ODDS_SCALE as u128;
+    let implied: u128 = odds
+        .iter()
+        .filter(|&&o| o > 0)
+        .map(|&o| scale * scale / o as u128)
+        .sum();
+
+    (implied.saturating_sub(scale) * BPS_DENOMINATOR as u128 / scale).min(u64::MAX as u128) as u64
+}
+
+/// Check an externally priced 1X2 book against the odds band and overround rules
+///
+/// Every price must sit inside [min_odds, max_odds] and the book's overround must be at
+/// least the house minimum (never an arbitrage) and at most MAX_OVERROUND_BPS
+pub fn is_valid_book(odds: &[u64], min_odds: u64, max_odds: u64, min_overround_bps: u16) -> bool {
+    if odds.iter().any(|&o| o < min_odds || o > max_odds) {
+        return false;
+    }
+
+    let scale = ODDS_SCALE as u128;
+    let implied: u128 = odds.iter().map(|&o| scale * scale / o as u128).sum();
+    if implied < scale {
+        return false;
+    }
+
+    let overround = book_overround_bps(odds);
+    overround >= min_overround_bps as u64 && overround <= MAX_OVERROUND_BPS as u64
 }
 
 /// Calculate locked odds from initial seed pools
@@ -43,6 +156,20 @@ pub fn calculate_locked_odds_from_seeds(
     home_seed: u64,
     away_seed: u64,
     draw_seed: u64,
+) -> (u64, u64, u64) {
+    calculate_profiled_odds_from_seeds(home_seed, away_seed, draw_seed, &OddsCurve::default(), 0)
+}
+
+/// Calculate locked odds from seed pools along an odds profile's curve
+///
+/// With a target overround the compressed book is re-margined: each price becomes the
+/// book's normalised probability less the margin, kept inside the curve's odds band
+pub fn calculate_profiled_odds_from_seeds(
+    home_seed: u64,
+    away_seed: u64,
+    draw_seed: u64,
+    curve: &OddsCurve,
+    overround_bps: u16,
 ) -> (u64, u64, u64) {
     let total_pool = home_seed + away_seed + draw_seed;
 
@@ -75,65 +202,214 @@ pub fn calculate_locked_odds_from_seeds(
         .unwrap_or(ODDS_SCALE as u128) as u64;
 
     // Compress to target range
+    let compressed = LockedOdds {
+        home_odds: compress_odds_on_curve(raw_home_odds, curve),
+        away_odds: compress_odds_on_curve(raw_away_odds, curve),
+        draw_odds: compress_odds_on_curve(raw_draw_odds, curve),
+        locked: true,
+    };
+
+    if overround_bps == 0 {
+        return (compressed.home_odds, compressed.away_odds, compressed.draw_odds);
+    }
+
+    let margin_bps = overround_to_margin_bps(overround_bps);
+    let (home, away, draw) = implied_probabilities(&compressed);
     (
-        compress_odds(raw_home_odds),
-        compress_odds(raw_away_odds),
-        compress_odds(raw_draw_odds),
+        odds_from_probability(home, margin_bps, curve.min_odds, curve.max_odds),
+        odds_from_probability(away, margin_bps, curve.min_odds, curve.max_odds),
+        odds_from_probability(draw, margin_bps, curve.min_odds, curve.max_odds),
     )
 }
 
-/// Calculate market odds with virtual liquidity dampening
+/// Calculate current market odds with virtual liquidity dampening
 ///
-/// Used for previewing odds before betting
-pub fn calculate_market_odds(pool: &MatchPool, outcome: u8) -> u64 {
-    let winning_pool = pool.get_pool_amount(outcome);
-    let total_pool = pool.total_pool;
+/// Virtual liquidity is spread across the selections by the implied probability of
+/// their seeded odds, so an untraded market quotes its (de-margined) seeded book and
+/// stake flow moves the price from there. Used for previews and cash-out quotes.
+pub fn calculate_market_odds(market: &Market, outcome: u8) -> u64 {
+    let selection = match market.selection(outcome) {
+        Some(selection) if selection.seed_odds > 0 => selection,
+        _ => return 0,
+    };
+
+    let scale = ODDS_SCALE as u128;
+    let implied = |odds: u64| if odds == 0 { 0 } else { scale * scale / odds as u128 };
+    let total_implied: u128 = market.selections[..market.num_selections as usize]
+        .iter()
+        .map(|s| implied(s.seed_odds))
+        .sum();
+
+    // Apply virtual liquidity to dampen price impact (u128: the product exceeds u64::MAX)
+    let virtual_liquidity = (market.seed_liquidity as u128).saturating_mul(VIRTUAL_LIQUIDITY_MULTIPLIER as u128);
+    let virtual_selection_pool = virtual_liquidity
+        .saturating_mul(implied(selection.seed_odds))
+        .checked_div(total_implied)
+        .unwrap_or(0);
+
+    let virtual_winning_pool = (selection.pool as u128).saturating_add(virtual_selection_pool);
+    let virtual_total_pool = (market.total_pool as u128).saturating_add(virtual_liquidity);
 
-    if winning_pool == 0 {
-        return 3 * ODDS_SCALE; // Fallback: fair VRF odds (33.33% = 3.0x)
+    // Calculate dampened odds
+    virtual_total_pool
+        .saturating_mul(scale)
+        .checked_div(virtual_winning_pool)
+        .unwrap_or(scale)
+        .min(u64::MAX as u128) as u64
+}
+
+/// Reprice a selection from the book's net exposure on it
+///
+/// Positive exposure (the book loses if the selection wins) shortens the price and
+/// negative exposure lengthens it, linearly up to the full band at
+/// `exposure_scale` (the match's seed liquidity). The price never leaves seed ± band.
+pub fn calculate_repriced_odds(seed_odds: u64, exposure: i64, band_bps: u16, exposure_scale: u64) -> u64 {
+    if band_bps == 0 || seed_odds == 0 || exposure_scale == 0 {
+        return seed_odds;
     }
 
-    // Apply virtual liquidity to dampen price impact
-    // Use u128 to prevent overflow: SEED_PER_MATCH * VIRTUAL_LIQUIDITY_MULTIPLIER can exceed u64::MAX
-    let virtual_liquidity = ((SEED_PER_MATCH as u128)
-        .checked_mul(VIRTUAL_LIQUIDITY_MULTIPLIER as u128)
-        .unwrap_or(0))
-        .min(u64::MAX as u128) as u64;
+    let band = band_bps as i128;
+    let shift_bps = (exposure as i128 * band / exposure_scale as i128).clamp(-band, band);
 
-    // Add virtual liquidity proportionally (33.33% per outcome)
-    let virtual_winning_pool = winning_pool + (virtual_liquidity / 3);
-    let virtual_total_pool = total_pool + virtual_liquidity;
+    let repriced = seed_odds as i128 * (BPS_DENOMINATOR as i128 - shift_bps) / BPS_DENOMINATOR as i128;
 
-    // Calculate dampened odds
-    (virtual_total_pool as u128)
-        .checked_mul(ODDS_SCALE as u128)
-        .unwrap_or(0)
-        .checked_div(virtual_winning_pool as u128)
-        .unwrap_or(ODDS_SCALE as u128) as u64
+    (repriced as u64).max(MIN_REPRICED_ODDS.min(seed_odds))
 }
 
-/// Calculate pool imbalance (measures dominance of largest pool)
+/// Calculate market imbalance (measures dominance of largest selection pool)
 ///
 /// Returns imbalance in basis points (0-10000, where 10000 = 100%)
-pub fn calculate_pool_imbalance(pool: &MatchPool) -> u64 {
-    if pool.total_pool == 0 {
+pub fn calculate_market_imbalance(market: &Market) -> u64 {
+    if market.total_pool == 0 {
         return 0;
     }
 
-    // Find max pool
-    let max_pool = pool
-        .home_win_pool
-        .max(pool.away_win_pool)
-        .max(pool.draw_pool);
+    // Find max selection pool
+    let max_pool = market.selections[..market.num_selections as usize]
+        .iter()
+        .map(|selection| selection.pool)
+        .max()
+        .unwrap_or(0);
 
     // Return as basis points
     (max_pool as u128)
         .checked_mul(BPS_DENOMINATOR as u128)
         .unwrap_or(0)
-        .checked_div(pool.total_pool as u128)
+        .checked_div(market.total_pool as u128)
         .unwrap_or(0) as u64
 }
 
+/// Calculate locked odds for a season outright stake
+///
+/// Parimutuel across all teams, dampened by a virtual stake per team (in the
+/// betting mint's units) and clamped to the outright odds band. The returned price is locked per stake.
+pub fn calculate_outright_odds(team_pools: &[u64], team: usize, virtual_stake: u64) -> u64 {
+    if team >= team_pools.len() {
+        return MIN_OUTRIGHT_ODDS;
+    }
+
+    let total_staked = team_pools
+        .iter()
+        .fold(0u128, |acc, &pool| acc.saturating_add(pool as u128));
+
+    let virtual_total = total_staked
+        .saturating_add(virtual_stake as u128 * team_pools.len() as u128);
+    let virtual_team_pool = (team_pools[team] as u128)
+        .saturating_add(virtual_stake as u128);
+
+    let raw_odds = virtual_total
+        .saturating_mul(ODDS_SCALE as u128)
+        .checked_div(virtual_team_pool)
+        .unwrap_or(ODDS_SCALE as u128)
+        .min(u64::MAX as u128) as u64;
+
+    raw_odds.clamp(MIN_OUTRIGHT_ODDS, MAX_OUTRIGHT_ODDS)
+}
+
+/// Locked odds for an outright stake, including the stake's own price impact
+///
+/// Average of the team's price before and after the stake joins its pool,
+/// so a large stake can't lock the whole of it at the pre-impact price
+pub fn calculate_outright_stake_odds(team_pools: &[u64], team: usize, stake: u64, virtual_stake: u64) -> u64 {
+    if team >= team_pools.len() {
+        return MIN_OUTRIGHT_ODDS;
+    }
+
+    let before = calculate_outright_odds(team_pools, team, virtual_stake);
+
+    let mut after_pools = team_pools.to_vec();
+    after_pools[team] = after_pools[team].saturating_add(stake);
+    let after = calculate_outright_odds(&after_pools, team, virtual_stake);
+
+    ((before as u128 + after as u128) / 2) as u64
+}
+
+/// Normalised implied probabilities (home, away, draw) from locked 1X2 odds
+///
+/// Locked odds are compressed, so raw 1/odds over-round heavily; normalising
+/// restores a probability split summing to 1e9
+pub fn implied_probabilities(odds: &LockedOdds) -> (u64, u64, u64) {
+    let scale = ODDS_SCALE as u128;
+    let implied = |o: u64| if o == 0 { 0 } else { scale * scale / o as u128 };
+
+    let home = implied(odds.home_odds);
+    let away = implied(odds.away_odds);
+    let draw = implied(odds.draw_odds);
+    let total = home + away + draw;
+
+    if total == 0 {
+        let third = ODDS_SCALE / 3;
+        return (third, third, third);
+    }
+
+    (
+        (home * scale / total) as u64,
+        (away * scale / total) as u64,
+        (draw * scale / total) as u64,
+    )
+}
+
+/// Calculate double chance odds (1=1X, 2=X2, 3=12) from locked 1X2 odds
+///
+/// Priced on the combined probability with margin, and never longer than
+/// either covered 1X2 price (a double chance covers strictly more outcomes)
+pub fn calculate_double_chance_odds(odds: &LockedOdds, outcome: u8, margin_bps: u64) -> u64 {
+    let (home, away, draw) = implied_probabilities(odds);
+
+    let (probability, cap) = match outcome {
+        OUTCOME_HOME_OR_DRAW => (home + draw, odds.home_odds.min(odds.draw_odds)),
+        OUTCOME_AWAY_OR_DRAW => (away + draw, odds.away_odds.min(odds.draw_odds)),
+        OUTCOME_HOME_OR_AWAY => (home + away, odds.home_odds.min(odds.away_odds)),
+        _ => return 0,
+    };
+
+    odds_from_probability(probability, margin_bps, MIN_DERIVED_MARKET_ODDS, MAX_COMPRESSED_ODDS)
+        .min(cap.max(MIN_DERIVED_MARKET_ODDS))
+}
+
+/// Calculate draw no bet odds (1=HOME, 2=AWAY) from locked 1X2 odds
+///
+/// Priced on the probability conditional on no draw, with margin, and never
+/// longer than the plain 1X2 price for the same side
+pub fn calculate_draw_no_bet_odds(odds: &LockedOdds, outcome: u8, margin_bps: u64) -> u64 {
+    let (home, away, _) = implied_probabilities(odds);
+    let decisive = home + away;
+    if decisive == 0 {
+        return 0;
+    }
+
+    let (side, cap) = match outcome {
+        1 => (home, odds.home_odds),
+        2 => (away, odds.away_odds),
+        _ => return 0,
+    };
+
+    let probability = (side as u128 * ODDS_SCALE as u128 / decisive as u128) as u64;
+
+    odds_from_probability(probability, margin_bps, MIN_DERIVED_MARKET_ODDS, MAX_COMPRESSED_ODDS)
+        .min(cap.max(MIN_DERIVED_MARKET_ODDS))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +540,286 @@ mod tests {
         assert_eq!(away, 1_500_000_000);
         assert_eq!(draw, 1_500_000_000);
     }
+
+    #[test]
+    fn test_outright_odds_empty_book_capped() {
+        // Empty book prices every team at 10x, which is clamped to the ceiling
+        let pools = [0u64; NUM_TEAMS];
+        for team in 0..NUM_TEAMS {
+            assert_eq!(calculate_outright_odds(&pools, team, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM), MAX_OUTRIGHT_ODDS);
+        }
+    }
+
+    #[test]
+    fn test_outright_odds_shorten_with_stake() {
+        let mut pools = [0u64; NUM_TEAMS];
+        pools[3] = 5 * OUTRIGHT_VIRTUAL_STAKE_PER_TEAM;
+
+        let backed = calculate_outright_odds(&pools, 3, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+        let unbacked = calculate_outright_odds(&pools, 4, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+
+        assert!(backed < unbacked, "backed {} should be shorter than unbacked {}", backed, unbacked);
+        assert!(backed >= MIN_OUTRIGHT_ODDS && backed <= MAX_OUTRIGHT_ODDS);
+    }
+
+    #[test]
+    fn test_outright_stake_odds_include_own_impact() {
+        let mut pools = [0u64; NUM_TEAMS];
+        pools[3] = 5 * OUTRIGHT_VIRTUAL_STAKE_PER_TEAM;
+        let before = calculate_outright_odds(&pools, 3, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+
+        // A small stake barely moves the price
+        let small = calculate_outright_stake_odds(&pools, 3, 1_000_000_000, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+        assert!(before - small < before / 1_000, "small stake moved {} -> {}", before, small);
+
+        // A whale locks between the pre- and post-stake price, not the pre-impact price
+        let whale = 20 * OUTRIGHT_VIRTUAL_STAKE_PER_TEAM;
+        let mut after_pools = pools;
+        after_pools[3] += whale;
+        let after = calculate_outright_odds(&after_pools, 3, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+        let locked = calculate_outright_stake_odds(&pools, 3, whale, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM);
+        assert!(after < locked && locked < before, "{} < {} < {}", after, locked, before);
+        assert_eq!(locked, (before + after) / 2);
+    }
+
+    #[test]
+    fn test_outright_odds_scale_with_mint_decimals() {
+        use crate::utils::seeding::scale_token_amount;
+
+        // The same book in 6-decimal units prices the same as in 9-decimal units
+        let virtual_stake_6 = scale_token_amount(OUTRIGHT_VIRTUAL_STAKE_PER_TEAM, 6);
+        let mut pools_9 = [0u64; NUM_TEAMS];
+        let mut pools_6 = [0u64; NUM_TEAMS];
+        pools_9[3] = 5 * OUTRIGHT_VIRTUAL_STAKE_PER_TEAM;
+        pools_6[3] = 5 * virtual_stake_6;
+
+        assert_eq!(
+            calculate_outright_odds(&pools_6, 3, virtual_stake_6),
+            calculate_outright_odds(&pools_9, 3, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM)
+        );
+        assert!(calculate_outright_odds(&pools_6, 3, virtual_stake_6) < MAX_OUTRIGHT_ODDS);
+    }
+
+    #[test]
+    fn test_outright_odds_floor_for_heavy_favourite() {
+        let mut pools = [0u64; NUM_TEAMS];
+        pools[0] = 1_000 * OUTRIGHT_VIRTUAL_STAKE_PER_TEAM;
+        assert_eq!(calculate_outright_odds(&pools, 0, OUTRIGHT_VIRTUAL_STAKE_PER_TEAM), MIN_OUTRIGHT_ODDS);
+    }
+
+    fn locked(home: u64, away: u64, draw: u64) -> LockedOdds {
+        LockedOdds { home_odds: home, away_odds: away, draw_odds: draw, locked: true }
+    }
+
+    #[test]
+    fn test_double_chance_shorter_than_components() {
+        let odds = locked(1_400_000_000, 2_100_000_000, 1_800_000_000);
+
+        let home_or_draw = calculate_double_chance_odds(&odds, OUTCOME_HOME_OR_DRAW, DERIVED_MARKET_MARGIN_BPS);
+        let away_or_draw = calculate_double_chance_odds(&odds, OUTCOME_AWAY_OR_DRAW, DERIVED_MARKET_MARGIN_BPS);
+        let home_or_away = calculate_double_chance_odds(&odds, OUTCOME_HOME_OR_AWAY, DERIVED_MARKET_MARGIN_BPS);
+
+        assert!(home_or_draw <= odds.home_odds && home_or_draw <= odds.draw_odds);
+        assert!(away_or_draw <= odds.away_odds && away_or_draw <= odds.draw_odds);
+        assert!(home_or_away <= odds.home_odds && home_or_away <= odds.away_odds);
+        assert!(home_or_draw >= MIN_DERIVED_MARKET_ODDS);
+        // Favourite-or-draw is the safest selection
+        assert!(home_or_draw < away_or_draw);
+        assert_eq!(calculate_double_chance_odds(&odds, 4, DERIVED_MARKET_MARGIN_BPS), 0);
+    }
+
+    #[test]
+    fn test_draw_no_bet_between_floor_and_1x2() {
+        let odds = locked(1_400_000_000, 2_100_000_000, 1_800_000_000);
+
+        let home = calculate_draw_no_bet_odds(&odds, 1, DERIVED_MARKET_MARGIN_BPS);
+        let away = calculate_draw_no_bet_odds(&odds, 2, DERIVED_MARKET_MARGIN_BPS);
+
+        assert!(home >= MIN_DERIVED_MARKET_ODDS && home <= odds.home_odds);
+        assert!(away >= MIN_DERIVED_MARKET_ODDS && away <= odds.away_odds);
+        assert!(home < away, "favourite DNB {} should be shorter than underdog {}", home, away);
+        assert_eq!(calculate_draw_no_bet_odds(&odds, 3, DERIVED_MARKET_MARGIN_BPS), 0);
+    }
+
+    #[test]
+    fn test_repriced_odds_move_with_exposure_within_band() {
+        let seed = 2_000_000_000;
+
+        let scale = SEED_PER_MATCH;
+
+        assert_eq!(calculate_repriced_odds(seed, 0, 2_000, scale), seed);
+        assert_eq!(calculate_repriced_odds(seed, scale as i64, 0, scale), seed, "band 0 = fixed odds");
+
+        // Half the scale of exposure moves half the band: 2.0x → 1.8x / 2.2x
+        let half = (scale / 2) as i64;
+        assert_eq!(calculate_repriced_odds(seed, half, 2_000, scale), 1_800_000_000);
+        assert_eq!(calculate_repriced_odds(seed, -half, 2_000, scale), 2_200_000_000);
+
+        // Never beyond the band
+        let huge = 100 * scale as i64;
+        assert_eq!(calculate_repriced_odds(seed, huge, 2_000, scale), 1_600_000_000);
+        assert_eq!(calculate_repriced_odds(seed, -huge, 2_000, scale), 2_400_000_000);
+        assert_eq!(calculate_repriced_odds(1_050_000_000, huge, 5_000, scale), MIN_REPRICED_ODDS);
+
+        // The same exposure moves a thinly seeded match further
+        assert_eq!(calculate_repriced_odds(seed, half, 2_000, scale / 10), 1_600_000_000);
+    }
+
+    fn make_1x2_market(reprice_band_bps: u16, parimutuel: bool) -> Market {
+        let mut market = Market {
+            betting_pool: Default::default(),
+            round_id: 1,
+            match_index: 0,
+            market_type: MARKET_MATCH_RESULT,
+            num_selections: 3,
+            selections: [crate::state::MarketSelection::default(); MAX_MARKET_SELECTIONS],
+            total_pool: 0,
+            max_liability: 0,
+            reprice_band_bps,
+            seed_liquidity: SEED_PER_MATCH,
+            parimutuel,
+            resolved: false,
+            bump: 0,
+        };
+        for (selection, odds) in market.selections.iter_mut().zip([1_500_000_000u64, 2_000_000_000, 1_800_000_000]) {
+            selection.odds = odds;
+            selection.seed_odds = odds;
+        }
+        market
+    }
+
+    #[test]
+    fn test_market_reprices_after_one_sided_action() {
+        let mut market = make_1x2_market(2_000, false);
+
+        // Seed liquidity doesn't move the book
+        market.seed_stake(1, SEED_HOME_POOL).unwrap();
+        assert_eq!(market.odds(1), 1_500_000_000);
+
+        // A whale on the home side: home shortens, the other side lengthens
+        let whale = 1_000_000_000_000;
+        market.add_stake(1, whale, market.odds(1)).unwrap();
+        let home_after = market.odds(1);
+        assert!(home_after < 1_500_000_000);
+        assert!(market.odds(2) > 2_000_000_000);
+
+        // The whale keeps the price it took; the next bettor gets the new one
+        let liability = market.selections[0].liability;
+        market.add_stake(1, whale, home_after).unwrap();
+        assert_eq!(market.selections[0].liability - liability, (whale as u128 * home_after as u128 / ODDS_SCALE as u128) as u64);
+        assert!(market.odds(1) < home_after);
+
+        // Pulling both stakes out at their locked prices restores the seeded book
+        market.remove_stake(1, whale, home_after).unwrap();
+        market.remove_stake(1, whale, 1_500_000_000).unwrap();
+        assert_eq!(market.odds(1), 1_500_000_000);
+        assert_eq!(market.odds(2), 2_000_000_000);
+        assert_eq!(market.selections[0].pool, SEED_HOME_POOL);
+    }
+
+    #[test]
+    fn test_parimutuel_market_splits_pool_among_winners() {
+        let mut market = make_1x2_market(0, true);
+        market.add_stake(1, 600, market.odds(1)).unwrap();
+        market.add_stake(2, 300, market.odds(2)).unwrap();
+        market.add_stake(3, 100, market.odds(3)).unwrap();
+
+        // Away wins: 300 staked share the whole 1000 pool
+        market.resolve(&crate::state::MatchScore { home_goals: 0, away_goals: 1 });
+        let final_odds = market.settlement_odds(2).unwrap();
+        assert_eq!(final_odds, 1000 * ODDS_SCALE / 300);
+
+        // The book owes exactly the pool (less rounding), never more
+        let owed = market.total_owed().unwrap();
+        assert!(owed <= 1000 && owed >= 999, "owed {}", owed);
+
+        // Fixed-odds markets settle from the bet's own snapshot instead
+        assert_eq!(make_1x2_market(0, false).settlement_odds(2), None);
+    }
+
+    #[test]
+    fn test_odds_curves_stay_monotone_within_band() {
+        let linear = OddsCurve::default();
+        let logistic = OddsCurve { curve: ODDS_CURVE_LOGISTIC, curve_param: 6_000_000_000, ..linear };
+        let power = OddsCurve { curve: ODDS_CURVE_POWER, curve_param: 2_000_000_000, ..linear };
+
+        let mut previous = [0u64; 2];
+        for step in 0..=40u64 {
+            let raw = 1_500_000_000 + step * 110_000_000; // 1.5x → 5.9x raw
+
+            // The default profile is the original compression, bit for bit
+            assert_eq!(compress_odds_on_curve(raw, &linear), compress_odds(raw));
+
+            for (i, curve) in [logistic, power].iter().enumerate() {
+                let odds = compress_odds_on_curve(raw, curve);
+                assert!(odds >= MIN_COMPRESSED_ODDS && odds <= MAX_COMPRESSED_ODDS);
+                assert!(odds >= previous[i], "curve {} not monotone at raw {}", curve.curve, raw);
+                previous[i] = odds;
+            }
+        }
+
+        // Shaped curves still span the whole band
+        for curve in [logistic, power] {
+            assert_eq!(compress_odds_on_curve(RAW_ODDS_MIN, &curve), MIN_COMPRESSED_ODDS);
+            let top = compress_odds_on_curve(RAW_ODDS_MAX, &curve);
+            assert!(MAX_COMPRESSED_ODDS - top < 1_000, "curve {} tops out at {}", curve.curve, top);
+        }
+
+        // Logistic is symmetric about the middle; power 2 sits at a quarter of the band there
+        let mid_raw = (RAW_ODDS_MIN + RAW_ODDS_MAX) / 2;
+        let mid_odds = (MIN_COMPRESSED_ODDS + MAX_COMPRESSED_ODDS) / 2;
+        let quarter_odds = MIN_COMPRESSED_ODDS + (MAX_COMPRESSED_ODDS - MIN_COMPRESSED_ODDS) / 4;
+        assert!(compress_odds_on_curve(mid_raw, &logistic).abs_diff(mid_odds) < 1_000_000);
+        assert!(compress_odds_on_curve(mid_raw, &power).abs_diff(quarter_odds) < 1_000_000);
+    }
+
+    #[test]
+    fn test_profile_overround_targets_book_margin() {
+        // A wide band so the re-margined book isn't clamped
+        let curve = OddsCurve {
+            raw_odds_min: 1_000_000_000,
+            raw_odds_max: 20_000_000_000,
+            min_odds: 1_010_000_000,
+            max_odds: 20_000_000_000,
+            curve: ODDS_CURVE_LINEAR,
+            curve_param: 0,
+        };
+
+        let (home, away, draw) = calculate_profiled_odds_from_seeds(
+            SEED_HOME_POOL,
+            SEED_AWAY_POOL,
+            SEED_DRAW_POOL,
+            &curve,
+            500,
+        );
+        let overround = book_overround_bps(&[home, away, draw]);
+        assert!(overround.abs_diff(500) <= 2, "book overround {}bps, wanted 500", overround);
+        assert!(home < draw && draw < away);
+
+        // No overround target leaves the compressed book untouched
+        let untouched = calculate_profiled_odds_from_seeds(
+            SEED_HOME_POOL,
+            SEED_AWAY_POOL,
+            SEED_DRAW_POOL,
+            &OddsCurve::default(),
+            0,
+        );
+        assert_eq!(untouched, calculate_locked_odds_from_seeds(SEED_HOME_POOL, SEED_AWAY_POOL, SEED_DRAW_POOL));
+    }
+
+    #[test]
+    fn test_oracle_book_validation() {
+        let (min, max) = (1_010_000_000, 20_000_000_000);
+
+        // A 1.8 / 4.5 / 3.6 book carries ~5.8% overround
+        let book = [1_800_000_000, 4_500_000_000, 3_600_000_000];
+        assert!(is_valid_book(&book, min, max, 0));
+        assert!(is_valid_book(&book, min, max, 500));
+        assert!(!is_valid_book(&book, min, max, 600), "tighter than the house minimum");
+
+        // Arbitrage books and out-of-band prices are rejected
+        assert!(!is_valid_book(&[2_100_000_000, 4_500_000_000, 3_600_000_000], min, max, 0));
+        assert!(!is_valid_book(&book, min, 4_000_000_000, 0));
+        assert!(!is_valid_book(&[1_010_000_000; 3], min, max, 0), "overround above the ceiling");
+    }
 }
@@ -1,6 +1,5 @@
 use crate::constants::*;
-use crate::state::RoundAccounting;
-use crate::utils::odds::calculate_pool_imbalance;
+use crate::state::Bet;
 
 /// Get base parlay multiplier based on number of matches
 ///
@@ -51,25 +50,21 @@ pub fn get_reserve_decay_factor(_locked_reserve: u64) -> u64 {
 /// 1. Base multiplier from number of matches
 /// 2. Pool imbalance gating (economic protection)
 /// 3. Reserve-based decay (safety valve)
-pub fn calculate_parlay_multiplier_dynamic(
-    round_accounting: &RoundAccounting,
-    match_indices: &[u8],
-    num_legs: u8,
-) -> u64 {
-    // Validate match indices are within bounds
-    for &match_index in match_indices.iter() {
-        if match_index >= 10 {
-            return MIN_PARLAY_MULTIPLIER; // Return minimum on invalid input
-        }
-    }
-
+///
+/// `leg_imbalances` holds the pool imbalance (bps) of each leg's market
+pub fn calculate_parlay_multiplier_dynamic(leg_imbalances: &[u64]) -> u64 {
     // Prevent division by zero
-    if match_indices.is_empty() {
+    if leg_imbalances.is_empty() {
         return ODDS_SCALE;
     }
 
+    parlay_multiplier_for_legs(leg_imbalances.len() as u8, average_imbalance(leg_imbalances))
+}
+
+/// Parlay multiplier for a number of legs at a given average pool imbalance (bps)
+pub fn parlay_multiplier_for_legs(num_legs: u8, avg_imbalance: u64) -> u64 {
     // Single bets always get 1.0x
-    if num_legs == 1 {
+    if num_legs <= 1 {
         return ODDS_SCALE;
     }
 
@@ -77,14 +72,6 @@ pub fn calculate_parlay_multiplier_dynamic(
     let base_multiplier = get_base_parlay_multiplier(num_legs);
 
     // LAYER 2: Pool imbalance gating (ECONOMIC PROTECTION)
-    let mut total_imbalance = 0u64;
-    for &match_index in match_indices.iter() {
-        let pool = &round_accounting.match_pools[match_index as usize];
-        let imbalance = calculate_pool_imbalance(pool);
-        total_imbalance = total_imbalance.saturating_add(imbalance);
-    }
-    let avg_imbalance = total_imbalance / (match_indices.len() as u64);
-
     // If pools are balanced, reduce to minimum regardless of tier
     if avg_imbalance < MIN_IMBALANCE_FOR_FULL_BONUS {
         return MIN_PARLAY_MULTIPLIER; // 1.1x
@@ -106,53 +93,137 @@ pub fn calculate_parlay_multiplier_dynamic(
     }
 }
 
-/// Calculate odds-weighted allocations for parlay bets
+fn average_imbalance(leg_imbalances: &[u64]) -> u64 {
+    let total_imbalance = leg_imbalances
+        .iter()
+        .fold(0u64, |acc, &imbalance| acc.saturating_add(imbalance));
+    total_imbalance / (leg_imbalances.len() as u64)
+}
+
+/// Check a system bet's combination sizes fit its legs
 ///
-/// Allocates tokens such that each match contributes equally to target payout
-/// Returns (allocations, total_allocated, lp_borrowed)
-pub fn calculate_odds_weighted_allocations(
-    round_accounting: &RoundAccounting,
-    match_indices: &[u8],
-    outcomes: &[u8],
-    amount_after_fee: u64,
-    parlay_multiplier: u64,
-) -> Result<(Vec<u64>, u64, u64), &'static str> {
-    // Validate inputs
-    if match_indices.len() != outcomes.len() {
-        return Err("Match indices and outcomes must have same length");
+/// `system_sizes` has bit k-1 set for every k-fold included (0 = plain accumulator)
+pub fn is_valid_system(num_legs: u8, system_sizes: u16) -> bool {
+    if system_sizes == 0 {
+        return true;
     }
 
-    if match_indices.is_empty() || match_indices.len() > 10 {
-        return Err("Invalid number of matches (must be 1-10)");
+    num_legs >= 2
+        && num_legs as usize <= MAX_SYSTEM_SELECTIONS
+        && (system_sizes >> num_legs) == 0
+}
+
+/// All leg combinations of a system bet, as bitmasks over the legs
+pub fn system_combinations(num_legs: u8, system_sizes: u16) -> Vec<u16> {
+    (1u16..(1u16 << num_legs))
+        .filter(|combination| system_sizes & (1 << (combination.count_ones() - 1)) != 0)
+        .collect()
+}
+
+/// Parlay multiplier per combination size (index = size - 1) for a system bet
+///
+/// Every size is gated on the average imbalance across all of the bet's legs
+pub fn calculate_system_multipliers(leg_imbalances: &[u64], system_sizes: u16) -> Vec<u64> {
+    let mut multipliers = vec![0u64; leg_imbalances.len()];
+    if leg_imbalances.is_empty() {
+        return multipliers;
     }
 
-    // Validate all match indices and outcomes
-    for &match_index in match_indices.iter() {
-        if match_index >= 10 {
-            return Err("Invalid match index (must be 0-9)");
+    let avg_imbalance = average_imbalance(leg_imbalances);
+    for (i, multiplier) in multipliers.iter_mut().enumerate() {
+        if system_sizes & (1 << i) != 0 {
+            *multiplier = parlay_multiplier_for_legs(i as u8 + 1, avg_imbalance);
         }
     }
 
-    for &outcome in outcomes.iter() {
-        if outcome < 1 || outcome > 3 {
-            return Err("Invalid outcome (must be 1, 2, or 3)");
+    multipliers
+}
+
+/// Calculate allocations for a system bet
+///
+/// The stake is split evenly across every combination, each combination is
+/// allocated like an accumulator, and each leg's allocations are summed.
+/// Returns (allocations per leg, total_allocated, lp_borrowed)
+pub fn calculate_system_allocations(
+    leg_odds: &[u64],
+    system_sizes: u16,
+    amount_after_fee: u64,
+    multipliers: &[u64],
+) -> Result<(Vec<u64>, u64, u64), &'static str> {
+    if !is_valid_system(leg_odds.len() as u8, system_sizes) || system_sizes == 0 {
+        return Err("Invalid system bet");
+    }
+
+    let combinations = system_combinations(leg_odds.len() as u8, system_sizes);
+    let combination_stake = amount_after_fee / (combinations.len() as u64);
+
+    let mut allocations = vec![0u64; leg_odds.len()];
+    let mut total_allocated = 0u64;
+    for combination in combinations {
+        let legs = combination_legs(combination, leg_odds.len());
+        let odds: Vec<u64> = legs.iter().map(|&leg| leg_odds[leg]).collect();
+
+        let (combination_allocations, allocated, _) = calculate_odds_weighted_allocations(
+            &odds,
+            combination_stake,
+            multipliers[legs.len() - 1],
+        )?;
+
+        for (&leg, allocation) in legs.iter().zip(combination_allocations) {
+            allocations[leg] = allocations[leg].saturating_add(allocation);
         }
+        total_allocated = total_allocated.saturating_add(allocated);
     }
 
-    let mut allocations = Vec::with_capacity(match_indices.len());
+    let lp_borrowed = total_allocated.saturating_sub(amount_after_fee);
+
+    Ok((allocations, total_allocated, lp_borrowed))
+}
+
+/// Leg indices included in a combination bitmask
+pub fn combination_legs(combination: u16, num_legs: usize) -> Vec<usize> {
+    (0..num_legs).filter(|&leg| combination & (1 << leg) != 0).collect()
+}
+
+/// Has a bet lost outright? `lost_legs` has bit i set for every leg already lost
+///
+/// An accumulator dies with its first lost leg; a system bet only once every
+/// combination contains a lost leg
+pub fn is_bet_lost(num_legs: u8, system_sizes: u16, lost_legs: u16) -> bool {
+    if system_sizes == 0 {
+        return lost_legs != 0;
+    }
+
+    system_combinations(num_legs, system_sizes)
+        .iter()
+        .all(|combination| combination & lost_legs != 0)
+}
+
+/// Calculate odds-weighted allocations for parlay bets
+///
+/// Allocates tokens such that each match contributes equally to target payout
+/// Returns (allocations, total_allocated, lp_borrowed)
+pub fn calculate_odds_weighted_allocations(
+    leg_odds: &[u64],
+    amount_after_fee: u64,
+    parlay_multiplier: u64,
+) -> Result<(Vec<u64>, u64, u64), &'static str> {
+    // Validate inputs
+    if leg_odds.is_empty() || leg_odds.len() > MAX_LEGS_PER_BET {
+        return Err("Invalid number of legs (must be 1 to MAX_LEGS_PER_BET)");
+    }
+
+    let mut allocations = Vec::with_capacity(leg_odds.len());
 
     // Step 1: Calculate target final payout
     // Base payout = product of all odds
     let mut base_payout = amount_after_fee;
-    for (i, &match_index) in match_indices.iter().enumerate() {
-        let odds = &round_accounting.locked_odds[match_index as usize];
-        if !odds.locked {
+    for &match_odds in leg_odds.iter() {
+        // Locked odds for the predicted selection in the leg's market
+        if match_odds == 0 {
             return Err("Odds not locked - seed round first");
         }
 
-        // Get odds for predicted outcome
-        let match_odds = odds.get_odds(outcomes[i]);
-
         // Multiply: base_payout = base_payout × match_odds / ODDS_SCALE
         base_payout = (base_payout as u128)
             .checked_mul(match_odds as u128)
@@ -169,14 +240,11 @@ pub fn calculate_odds_weighted_allocations(
         .ok_or("Division error")? as u64;
 
     // Step 2: Calculate per-match contribution (equal contribution)
-    let per_match_contribution = target_payout / (match_indices.len() as u64);
+    let per_match_contribution = target_payout / (leg_odds.len() as u64);
 
     // Step 3: Calculate required allocation for each match (working backwards)
     let mut total_allocated = 0u64;
-    for (i, &match_index) in match_indices.iter().enumerate() {
-        let odds = &round_accounting.locked_odds[match_index as usize];
-        let match_odds = odds.get_odds(outcomes[i]);
-
+    for &match_odds in leg_odds.iter() {
         // Calculate: allocation = per_match_contribution / match_odds
         let allocation = (per_match_contribution as u128)
             .checked_mul(ODDS_SCALE as u128)
@@ -198,26 +266,133 @@ pub fn calculate_odds_weighted_allocations(
     Ok((allocations, total_allocated, lp_borrowed))
 }
 
+/// One accumulator combination of a bet
+pub struct BetCombination {
+    /// Legs included (bitmask over the bet's predictions)
+    pub legs: u16,
+
+    /// Allocation per included leg, in leg order
+    pub allocations: Vec<u64>,
+
+    /// Locked parlay multiplier for the combination
+    pub multiplier: u64,
+}
+
+/// Break a bet into the combinations it pays on
+///
+/// Accumulators are one combination of every leg at the stored allocations;
+/// system bets rebuild each combination exactly as at placement, from the legs' locked odds
+pub fn bet_combinations(bet: &Bet) -> Result<Vec<BetCombination>, &'static str> {
+    let predictions = bet.get_predictions();
+    if predictions.is_empty() {
+        return Err("Bet has no predictions");
+    }
+    let leg_odds: Vec<u64> = predictions.iter().map(|p| p.locked_odds).collect();
+
+    if bet.system_sizes == 0 {
+        return Ok(vec![BetCombination {
+            legs: (1u16 << predictions.len()) - 1,
+            allocations: predictions.iter().map(|p| p.amount_in_pool).collect(),
+            multiplier: bet.locked_multiplier,
+        }]);
+    }
+
+    let combinations = system_combinations(predictions.len() as u8, bet.system_sizes);
+    if combinations.is_empty() {
+        return Err("Invalid system bet");
+    }
+    let combination_stake = bet.amount_after_fee / (combinations.len() as u64);
+
+    combinations
+        .into_iter()
+        .map(|legs| {
+            let odds: Vec<u64> = combination_legs(legs, leg_odds.len())
+                .into_iter()
+                .map(|leg| leg_odds[leg])
+                .collect();
+            let multiplier = bet.system_multipliers[odds.len() - 1];
+            let (allocations, _, _) = calculate_odds_weighted_allocations(&odds, combination_stake, multiplier)?;
+            Ok(BetCombination { legs, allocations, multiplier })
+        })
+        .collect()
+}
+
+/// Quote to cash a bet out before settlement
+///
+/// `current_odds` holds each prediction's current market odds. Each combination is
+/// worth its full payout at the bet's locked odds × the chance all its legs win at
+/// current odds; the sum is the bet's fair value, less the house margin.
+pub fn calculate_cash_out_quote(
+    bet: &Bet,
+    current_odds: &[u64],
+    margin_bps: u64,
+    max_payout_per_bet: u64,
+) -> Result<u64, &'static str> {
+    let predictions = bet.get_predictions();
+    if current_odds.len() != predictions.len() {
+        return Err("Current odds must match predictions");
+    }
+    let scale = ODDS_SCALE as u128;
+
+    let mut fair_value = 0u128;
+    for combination in bet_combinations(bet)? {
+        let included = combination_legs(combination.legs, predictions.len());
+
+        // Full payout if every leg in the combination wins
+        let mut payout = 0u128;
+        for (&leg, &allocation) in included.iter().zip(combination.allocations.iter()) {
+            payout += (allocation as u128)
+                .checked_mul(predictions[leg].locked_odds as u128)
+                .ok_or("Cash out calculation overflow")?
+                / scale;
+        }
+        payout = (payout * combination.multiplier as u128 / scale).min(max_payout_per_bet as u128);
+
+        // Probability all legs win, implied by current odds
+        let mut probability = scale;
+        for &leg in included.iter() {
+            if current_odds[leg] == 0 {
+                return Err("Market odds unavailable");
+            }
+            probability = probability * scale / current_odds[leg] as u128;
+        }
+
+        fair_value += payout * probability / scale;
+    }
+
+    let quote = fair_value
+        .min(max_payout_per_bet as u128)
+        .checked_mul(BPS_DENOMINATOR.saturating_sub(margin_bps) as u128)
+        .ok_or("Cash out calculation overflow")?
+        / BPS_DENOMINATOR as u128;
+
+    Ok(quote as u64)
+}
+
 /// Calculate maximum possible payout for a bet
 ///
+/// Every combination winning at its legs' locked odds, times its locked multiplier.
 /// Used to check if LP pool can cover potential winnings
-pub fn calculate_max_payout(amount: u64, num_matches: u8, parlay_multiplier: u64) -> u64 {
-    // Pessimistic estimate: assume best case odds (2x per match)
-    let max_base_payout = amount.saturating_mul(2u64.saturating_pow(num_matches as u32));
-
-    // Apply parlay multiplier
-    let max_final_payout = (max_base_payout as u128)
-        .checked_mul(parlay_multiplier as u128)
-        .unwrap_or(0)
-        .checked_div(ODDS_SCALE as u128)
-        .unwrap_or(0) as u64;
+pub fn calculate_max_payout(bet: &Bet, max_payout_per_bet: u64) -> Result<u64, &'static str> {
+    let predictions = bet.get_predictions();
+    let scale = ODDS_SCALE as u128;
+
+    let mut max_payout = 0u128;
+    for combination in bet_combinations(bet)? {
+        let included = combination_legs(combination.legs, predictions.len());
+
+        let mut payout = 0u128;
+        for (&leg, &allocation) in included.iter().zip(combination.allocations.iter()) {
+            payout += (allocation as u128)
+                .checked_mul(predictions[leg].locked_odds as u128)
+                .ok_or("Max payout calculation overflow")?
+                / scale;
+        }
+        max_payout += payout * combination.multiplier as u128 / scale;
+    }
 
     // Apply per-bet cap
-    if max_final_payout > MAX_PAYOUT_PER_BET {
-        MAX_PAYOUT_PER_BET
-    } else {
-        max_final_payout
-    }
+    Ok(max_payout.min(max_payout_per_bet as u128) as u64)
 }
 
 #[cfg(test)]
@@ -231,6 +406,155 @@ mod tests {
         assert_eq!(get_base_parlay_multiplier(10), PARLAY_MULTIPLIER_10_MATCHES);
     }
 
+    #[test]
+    fn test_system_bet_combination_counts() {
+        // 2 from 3: three doubles
+        assert_eq!(system_combinations(3, 1 << 1).len(), 3);
+        assert_eq!(system_combinations(3, SYSTEM_TRIXIE).len(), 4);
+        assert_eq!(system_combinations(4, SYSTEM_YANKEE).len(), 11);
+        assert_eq!(system_combinations(4, SYSTEM_LUCKY_15).len(), 15);
+
+        assert!(is_valid_system(4, SYSTEM_LUCKY_15));
+        assert!(!is_valid_system(3, SYSTEM_YANKEE), "Yankee includes a four-fold");
+        assert!(!is_valid_system(1, 1));
+    }
+
+    #[test]
+    fn test_bet_lost_once_every_combination_has_a_lost_leg() {
+        assert!(!is_bet_lost(3, 0, 0));
+        assert!(is_bet_lost(3, 0, 0b100));
+
+        // Trixie survives one lost leg (one double still live), not two
+        assert!(!is_bet_lost(3, SYSTEM_TRIXIE, 0b001));
+        assert!(is_bet_lost(3, SYSTEM_TRIXIE, 0b011));
+
+        // Lucky 15 includes singles, so it only dies with every leg
+        assert!(!is_bet_lost(4, SYSTEM_LUCKY_15, 0b0111));
+        assert!(is_bet_lost(4, SYSTEM_LUCKY_15, 0b1111));
+    }
+
+    #[test]
+    fn test_system_allocations_split_stake_per_combination() {
+        let leg_odds = [2_000_000_000u64; 3];
+        let multipliers = [ODDS_SCALE; 3];
+
+        // Trixie: 3 doubles + 1 treble, 1000 split into 250 per combination
+        let (allocations, total, _) =
+            calculate_system_allocations(&leg_odds, SYSTEM_TRIXIE, 1_000, &multipliers).unwrap();
+
+        // Double: 250 × 4 / 2 legs / 2.0 = 250 per leg; treble: 250 × 8 / 3 / 2.0 = 333
+        assert_eq!(allocations, vec![2 * 250 + 333; 3]);
+        assert_eq!(total, 3 * (2 * 250 + 333));
+    }
+
+    #[test]
+    fn test_allocations_cover_accumulators_beyond_ten_legs() {
+        let leg_odds = [2_000_000_000u64; MAX_LEGS_PER_BET];
+        let (allocations, total, _) =
+            calculate_odds_weighted_allocations(&leg_odds, 1_000_000_000, ODDS_SCALE).unwrap();
+        assert_eq!(allocations.len(), MAX_LEGS_PER_BET);
+        assert!(total > 0);
+
+        let too_many = [2_000_000_000u64; MAX_LEGS_PER_BET + 1];
+        assert!(calculate_odds_weighted_allocations(&too_many, 1_000_000_000, ODDS_SCALE).is_err());
+    }
+
+    fn make_single_bet(amount_in_pool: u64, locked_odds: u64) -> Bet {
+        let predictions = vec![crate::state::Prediction {
+            round_id: 1,
+            match_index: 0,
+            market_type: MARKET_MATCH_RESULT,
+            predicted_outcome: 1,
+            amount_in_pool,
+            locked_odds,
+        }];
+
+        Bet {
+            bettor: Default::default(),
+            round_id: 1,
+            bet_id: 1,
+            amount: amount_in_pool,
+            amount_after_fee: amount_in_pool,
+            allocated_amount: amount_in_pool,
+            bonus: 0,
+            locked_multiplier: ODDS_SCALE,
+            num_predictions: 1,
+            predictions,
+            system_sizes: 0,
+            system_multipliers: vec![0],
+            settled: false,
+            claimed: false,
+            claim_deadline: 0,
+            bounty_claimer: None,
+            reserved_liability: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_cash_out_quote_tracks_current_odds() {
+        let bet = make_single_bet(1_000_000_000, 2_000_000_000);
+
+        // Locked 2.0x, still priced at 2.0x: fair value is the stake, less 5% margin
+        let quote = calculate_cash_out_quote(&bet, &[2_000_000_000], 500, MAX_PAYOUT_PER_BET).unwrap();
+        assert_eq!(quote, 950_000_000);
+
+        // Selection shortened to 1.25x: worth 2.0 / 1.25 = 1.6x the stake
+        let quote = calculate_cash_out_quote(&bet, &[1_250_000_000], 0, MAX_PAYOUT_PER_BET).unwrap();
+        assert_eq!(quote, 1_600_000_000);
+
+        assert!(calculate_cash_out_quote(&bet, &[0], 0, MAX_PAYOUT_PER_BET).is_err());
+    }
+
+    #[test]
+    fn test_partial_cash_out_reduces_stake_proportionally() {
+        let mut bet = make_single_bet(1_000_000_000, 2_000_000_000);
+        let full_quote = calculate_cash_out_quote(&bet, &[1_250_000_000], 0, MAX_PAYOUT_PER_BET).unwrap();
+
+        // Cash out 25%: a quarter of each leg comes out of its pool
+        let removed = bet.reduce_stake(2_500).unwrap();
+        assert_eq!(removed, vec![250_000_000]);
+        assert_eq!(bet.amount_after_fee, 750_000_000);
+        assert_eq!(bet.allocated_amount, 750_000_000);
+
+        // The remaining bet is worth the remaining share of the original quote
+        let quote = calculate_cash_out_quote(&bet, &[1_250_000_000], 0, MAX_PAYOUT_PER_BET).unwrap();
+        assert_eq!(quote, full_quote * 3 / 4);
+
+        assert!(bet.reduce_stake(0).is_err());
+        assert!(bet.reduce_stake(BPS_DENOMINATOR).is_err());
+    }
+
+    #[test]
+    fn test_max_payout_uses_locked_odds() {
+        // A 50x correct score single reserves 50x the stake, not 2x
+        let bet = make_single_bet(1_000_000_000, 50 * ODDS_SCALE);
+        assert_eq!(calculate_max_payout(&bet, MAX_PAYOUT_PER_BET).unwrap(), 50_000_000_000);
+
+        // Treble at 2.0x each with a 1.1x multiplier: 8x × 1.1
+        let leg_odds = [2_000_000_000u64; 3];
+        let (allocations, total, _) =
+            calculate_odds_weighted_allocations(&leg_odds, 1_000_000_000, 1_100_000_000).unwrap();
+        let mut treble = make_single_bet(1_000_000_000, 2_000_000_000);
+        treble.predictions = allocations
+            .iter()
+            .enumerate()
+            .map(|(i, &allocation)| crate::state::Prediction {
+                match_index: i as u8,
+                amount_in_pool: allocation,
+                ..treble.predictions[0]
+            })
+            .collect();
+        treble.num_predictions = 3;
+        treble.allocated_amount = total;
+        treble.locked_multiplier = 1_100_000_000;
+        let max_payout = calculate_max_payout(&treble, MAX_PAYOUT_PER_BET).unwrap();
+        assert!(max_payout.abs_diff(9_680_000_000) <= 10, "max payout {}", max_payout);
+
+        // Capped per bet
+        assert_eq!(calculate_max_payout(&bet, 10_000_000_000).unwrap(), 10_000_000_000);
+    }
+
     #[test]
     fn test_count_based_multiplier() {
         assert_eq!(get_parlay_multiplier_by_count(0), COUNT_MULT_TIER_1);
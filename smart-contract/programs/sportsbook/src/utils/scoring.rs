@@ -0,0 +1,406 @@
+use crate::constants::*;
+use crate::state::{LockedOdds, MatchScore, correct_score_outcome};
+
+/// e^-1 scaled by 1e9
+const EXP_NEG_ONE: u128 = 367_879_441;
+
+/// Fixed-point e^-x (x and result scaled by 1e9)
+///
+/// Integer part by repeated multiplication with e^-1, fractional part by Taylor series
+pub fn exp_neg(x: u64) -> u64 {
+    let scale = ODDS_SCALE as u128;
+    let whole = x / ODDS_SCALE;
+    let frac = (x % ODDS_SCALE) as u128;
+
+    // e^-frac = Σ (-frac)^k / k!  (converges fast for frac < 1)
+    let mut term = scale;
+    let mut sum = scale as i128;
+    for k in 1..=12u128 {
+        term = term * frac / (scale * k);
+        if k % 2 == 1 {
+            sum -= term as i128;
+        } else {
+            sum += term as i128;
+        }
+    }
+
+    let mut result = sum.max(0) as u128;
+    for _ in 0..whole {
+        result = result * EXP_NEG_ONE / scale;
+        if result == 0 {
+            break;
+        }
+    }
+
+    result as u64
+}
+
+/// Expected goals (home, away) for a match, derived from its locked 1X2 odds
+///
+/// Relative strength comes from the implied home/away probabilities.
+/// Mismatched games are expected to have more goals than even ones.
+pub fn expected_goals_from_odds(odds: &LockedOdds) -> (u64, u64) {
+    let scale = ODDS_SCALE as u128;
+
+    if odds.home_odds == 0 || odds.away_odds == 0 {
+        let half = BASE_EXPECTED_GOALS / 2;
+        return (half, half);
+    }
+
+    // Implied probabilities (scaled by 1e9)
+    let home_prob = scale * scale / odds.home_odds as u128;
+    let away_prob = scale * scale / odds.away_odds as u128;
+    let home_share = home_prob * scale / (home_prob + away_prob);
+
+    // |home_share - away_share| in [0, 1e9]
+    let mismatch = (2 * home_share).abs_diff(scale);
+    let total = BASE_EXPECTED_GOALS as u128 + MISMATCH_EXPECTED_GOALS as u128 * mismatch / scale;
+
+    let home_rate = (total * home_share / scale) as u64;
+    let away_rate = (total as u64).saturating_sub(home_rate);
+
+    (
+        home_rate.max(MIN_TEAM_GOAL_RATE),
+        away_rate.max(MIN_TEAM_GOAL_RATE),
+    )
+}
+
+/// Probability that a Poisson(rate) variable is at most `max_goals` (scaled by 1e9)
+pub fn poisson_cdf(rate: u64, max_goals: u8) -> u64 {
+    let scale = ODDS_SCALE as u128;
+    let mut probability = exp_neg(rate) as u128;
+    let mut cumulative = probability;
+
+    for k in 1..=max_goals as u128 {
+        probability = probability * rate as u128 / (scale * k);
+        cumulative += probability;
+    }
+
+    cumulative.min(scale) as u64
+}
+
+/// Draw a goal count from Poisson(rate) by inverting the CDF
+///
+/// `random` is mapped uniformly onto [0, 1). Capped at MAX_GOALS_PER_TEAM.
+pub fn sample_poisson(rate: u64, random: u64) -> u8 {
+    let scale = ODDS_SCALE as u128;
+    let uniform = (random as u128 * scale) >> 64;
+
+    let mut probability = exp_neg(rate) as u128;
+    let mut cumulative = probability;
+    let mut goals = 0u8;
+
+    while uniform >= cumulative && goals < MAX_GOALS_PER_TEAM {
+        goals += 1;
+        probability = probability * rate as u128 / (scale * goals as u128);
+        cumulative += probability;
+    }
+
+    goals
+}
+
+/// Simulate a scoreline from 32 bytes of randomness and the match's locked odds
+///
+/// Bytes 0-7 drive home goals, bytes 8-15 drive away goals
+pub fn simulate_score(randomness: &[u8], odds: &LockedOdds) -> MatchScore {
+    let (home_rate, away_rate) = expected_goals_from_odds(odds);
+
+    let home_random = u64::from_le_bytes(randomness[0..8].try_into().unwrap());
+    let away_random = u64::from_le_bytes(randomness[8..16].try_into().unwrap());
+
+    MatchScore {
+        home_goals: sample_poisson(home_rate, home_random),
+        away_goals: sample_poisson(away_rate, away_random),
+    }
+}
+
+/// Convert a probability (scaled by 1e9) into odds after a bookmaker margin
+///
+/// Clamped to the market's odds band
+pub fn odds_from_probability(probability: u64, margin_bps: u64, min_odds: u64, max_odds: u64) -> u64 {
+    if probability == 0 {
+        return max_odds;
+    }
+
+    let fair_odds = (ODDS_SCALE as u128 * ODDS_SCALE as u128) / probability as u128;
+    let odds = fair_odds * (BPS_DENOMINATOR - margin_bps) as u128 / BPS_DENOMINATOR as u128;
+
+    (odds.min(u64::MAX as u128) as u64).clamp(min_odds, max_odds)
+}
+
+/// Calculate locked over/under odds for a match from its locked 1X2 odds
+///
+/// Total goals of two independent Poisson teams is Poisson(home + away rate)
+/// Returns (over_odds, under_odds)
+pub fn calculate_totals_odds(odds: &LockedOdds, margin_bps: u64) -> (u64, u64) {
+    let (home_rate, away_rate) = expected_goals_from_odds(odds);
+
+    let under_probability = poisson_cdf(home_rate + away_rate, OVER_UNDER_LINE_GOALS);
+    let over_probability = ODDS_SCALE - under_probability;
+
+    (
+        odds_from_probability(over_probability, margin_bps, MIN_COMPRESSED_ODDS, MAX_COMPRESSED_ODDS),
+        odds_from_probability(under_probability, margin_bps, MIN_COMPRESSED_ODDS, MAX_COMPRESSED_ODDS),
+    )
+}
+
+/// Poisson probabilities of 0..N-1 goals (scaled by 1e9)
+pub fn poisson_table<const N: usize>(rate: u64) -> [u64; N] {
+    let scale = ODDS_SCALE as u128;
+    let mut probabilities = [0u64; N];
+    let mut probability = exp_neg(rate) as u128;
+
+    for (goals, slot) in probabilities.iter_mut().enumerate() {
+        if goals > 0 {
+            probability = probability * rate as u128 / (scale * goals as u128);
+        }
+        *slot = probability as u64;
+    }
+
+    probabilities
+}
+
+/// Poisson probabilities of 0..=CORRECT_SCORE_MAX_GOALS goals (scaled by 1e9)
+pub fn poisson_probabilities(rate: u64) -> [u64; CORRECT_SCORE_MAX_GOALS as usize + 1] {
+    poisson_table(rate)
+}
+
+/// Calculate locked correct score odds for a match from its locked 1X2 odds
+///
+/// Grid probabilities are the product of the two teams' Poisson goal probabilities;
+/// "any other" takes the remaining probability mass. Index = outcome - 1.
+pub fn calculate_correct_score_odds(odds: &LockedOdds, margin_bps: u64) -> [u64; NUM_CORRECT_SCORE_OUTCOMES] {
+    let (home_rate, away_rate) = expected_goals_from_odds(odds);
+    let home_probabilities = poisson_probabilities(home_rate);
+    let away_probabilities = poisson_probabilities(away_rate);
+
+    let mut score_odds = [0u64; NUM_CORRECT_SCORE_OUTCOMES];
+    let mut grid_probability = 0u64;
+
+    for (home_goals, &home_probability) in home_probabilities.iter().enumerate() {
+        for (away_goals, &away_probability) in away_probabilities.iter().enumerate() {
+            let probability = (home_probability as u128 * away_probability as u128
+                / ODDS_SCALE as u128) as u64;
+            grid_probability += probability;
+
+            let outcome = correct_score_outcome(home_goals as u8, away_goals as u8);
+            score_odds[(outcome - 1) as usize] = odds_from_probability(
+                probability,
+                margin_bps,
+                MIN_CORRECT_SCORE_ODDS,
+                MAX_CORRECT_SCORE_ODDS,
+            );
+        }
+    }
+
+    score_odds[(CORRECT_SCORE_ANY_OTHER - 1) as usize] = odds_from_probability(
+        ODDS_SCALE.saturating_sub(grid_probability),
+        margin_bps,
+        MIN_CORRECT_SCORE_ODDS,
+        MAX_CORRECT_SCORE_ODDS,
+    );
+
+    score_odds
+}
+
+/// Calculate locked both-teams-to-score odds for a match from its locked 1X2 odds
+///
+/// P(YES) = P(home scores) × P(away scores) under the same Poisson model
+/// Returns (yes_odds, no_odds)
+pub fn calculate_btts_odds(odds: &LockedOdds, margin_bps: u64) -> (u64, u64) {
+    let (home_rate, away_rate) = expected_goals_from_odds(odds);
+
+    let home_scores = ODDS_SCALE - exp_neg(home_rate);
+    let away_scores = ODDS_SCALE - exp_neg(away_rate);
+    let yes_probability = (home_scores as u128 * away_scores as u128 / ODDS_SCALE as u128) as u64;
+
+    (
+        odds_from_probability(yes_probability, margin_bps, MIN_COMPRESSED_ODDS, MAX_COMPRESSED_ODDS),
+        odds_from_probability(ODDS_SCALE - yes_probability, margin_bps, MIN_COMPRESSED_ODDS, MAX_COMPRESSED_ODDS),
+    )
+}
+
+/// Goal difference (home - away) distribution, index = difference + MAX_GOALS_PER_TEAM
+pub fn goal_difference_distribution(home_rate: u64, away_rate: u64) -> [u64; 2 * MAX_GOALS_PER_TEAM as usize + 1] {
+    let home_table: [u64; MAX_GOALS_PER_TEAM as usize + 1] = poisson_table(home_rate);
+    let away_table: [u64; MAX_GOALS_PER_TEAM as usize + 1] = poisson_table(away_rate);
+
+    let mut distribution = [0u64; 2 * MAX_GOALS_PER_TEAM as usize + 1];
+    for (home_goals, &home_probability) in home_table.iter().enumerate() {
+        for (away_goals, &away_probability) in away_table.iter().enumerate() {
+            let index = MAX_GOALS_PER_TEAM as usize + home_goals - away_goals;
+            distribution[index] += (home_probability as u128 * away_probability as u128
+                / ODDS_SCALE as u128) as u64;
+        }
+    }
+
+    distribution
+}
+
+/// Fair-value odds of backing home on a handicap line (quarter goals), less `margin_bps`
+///
+/// Each half of the stake settles on one neighbouring whole/half line (both halves
+/// on the same line when it isn't a quarter line). Fair odds make the expected
+/// return 1: odds = (2 - push1 - push2) / (win1 + win2).
+fn handicap_odds(distribution: &[u64], handicap_quarters: i16, margin_bps: u64) -> u64 {
+    let halves = if handicap_quarters % 2 == 0 {
+        [handicap_quarters, handicap_quarters]
+    } else {
+        [handicap_quarters - 1, handicap_quarters + 1]
+    };
+
+    let mut win = 0u128;
+    let mut push = 0u128;
+    for half in halves {
+        for (index, &probability) in distribution.iter().enumerate() {
+            let goal_difference = index as i16 - MAX_GOALS_PER_TEAM as i16;
+            let adjusted = goal_difference * 4 + half;
+            if adjusted > 0 {
+                win += probability as u128;
+            } else if adjusted == 0 {
+                push += probability as u128;
+            }
+        }
+    }
+
+    if win == 0 {
+        return MAX_ASIAN_HANDICAP_ODDS;
+    }
+
+    let scale = ODDS_SCALE as u128;
+    let fair_odds = (2 * scale).saturating_sub(push) * scale / win;
+    let odds = fair_odds * (BPS_DENOMINATOR - margin_bps) as u128
+        / BPS_DENOMINATOR as u128;
+
+    (odds.min(u64::MAX as u128) as u64).clamp(MIN_ASIAN_HANDICAP_ODDS, MAX_ASIAN_HANDICAP_ODDS)
+}
+
+/// Calculate locked Asian handicap odds for a match from its locked 1X2 odds
+///
+/// Index = outcome - 1 = line_index × 2 + side (side 0=HOME, 1=AWAY)
+pub fn calculate_asian_handicap_odds(odds: &LockedOdds, margin_bps: u64) -> [u64; NUM_ASIAN_HANDICAP_OUTCOMES] {
+    let (home_rate, away_rate) = expected_goals_from_odds(odds);
+    let distribution = goal_difference_distribution(home_rate, away_rate);
+
+    // Away side sees the mirrored distribution and the opposite line
+    let mut mirrored = distribution;
+    mirrored.reverse();
+
+    let mut handicap_odds_by_outcome = [0u64; NUM_ASIAN_HANDICAP_OUTCOMES];
+    for (line_index, &line) in ASIAN_HANDICAP_LINES.iter().enumerate() {
+        handicap_odds_by_outcome[line_index * 2] = handicap_odds(&distribution, line as i16, margin_bps);
+        handicap_odds_by_outcome[line_index * 2 + 1] = handicap_odds(&mirrored, -(line as i16), margin_bps);
+    }
+
+    handicap_odds_by_outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locked(home: u64, away: u64, draw: u64) -> LockedOdds {
+        LockedOdds { home_odds: home, away_odds: away, draw_odds: draw, locked: true }
+    }
+
+    #[test]
+    fn test_exp_neg_known_values() {
+        assert_eq!(exp_neg(0), ODDS_SCALE);
+        assert!(exp_neg(ODDS_SCALE).abs_diff(367_879_441) <= 2);
+        // e^-2.6 ≈ 0.0742736
+        assert!(exp_neg(2_600_000_000).abs_diff(74_273_578) <= 5);
+    }
+
+    #[test]
+    fn test_poisson_cdf_matches_closed_form() {
+        // P(X ≤ 2) for λ = 2.6: e^-2.6 × (1 + 2.6 + 3.38) ≈ 0.518430
+        let cdf = poisson_cdf(2_600_000_000, 2);
+        assert!(cdf.abs_diff(518_429_574) <= 10, "cdf = {}", cdf);
+    }
+
+    #[test]
+    fn test_sample_poisson_extremes() {
+        assert_eq!(sample_poisson(1_300_000_000, 0), 0);
+        assert_eq!(sample_poisson(1_300_000_000, u64::MAX), MAX_GOALS_PER_TEAM);
+    }
+
+    #[test]
+    fn test_favourite_expected_to_score_more() {
+        let (home, away) = expected_goals_from_odds(&locked(1_200_000_000, 2_200_000_000, 1_700_000_000));
+        assert!(home > away, "home {} should exceed away {}", home, away);
+        assert!(home + away > BASE_EXPECTED_GOALS, "mismatch adds goals");
+
+        let (home, away) = expected_goals_from_odds(&locked(1_500_000_000, 1_500_000_000, 1_700_000_000));
+        assert_eq!(home, away);
+        assert_eq!(home + away, BASE_EXPECTED_GOALS);
+    }
+
+    #[test]
+    fn test_totals_odds_in_range() {
+        let (over, under) = calculate_totals_odds(&locked(1_500_000_000, 1_500_000_000, 1_700_000_000), OVER_UNDER_MARGIN_BPS);
+        assert!(over >= MIN_COMPRESSED_ODDS && over <= MAX_COMPRESSED_ODDS);
+        assert!(under >= MIN_COMPRESSED_ODDS && under <= MAX_COMPRESSED_ODDS);
+        // λ = 2.6 makes under slightly more likely than over
+        assert!(under < over, "under {} should be shorter than over {}", under, over);
+    }
+
+    #[test]
+    fn test_correct_score_odds_follow_model() {
+        let odds = calculate_correct_score_odds(&locked(1_200_000_000, 2_200_000_000, 1_700_000_000), CORRECT_SCORE_MARGIN_BPS);
+
+        for &o in odds.iter() {
+            assert!(o >= MIN_CORRECT_SCORE_ODDS && o <= MAX_CORRECT_SCORE_ODDS);
+        }
+
+        // Favourite winning 1-0 is likelier than losing 0-1
+        let home_1_0 = odds[(correct_score_outcome(1, 0) - 1) as usize];
+        let away_0_1 = odds[(correct_score_outcome(0, 1) - 1) as usize];
+        assert!(home_1_0 < away_0_1, "1-0 {} should be shorter than 0-1 {}", home_1_0, away_0_1);
+    }
+
+    #[test]
+    fn test_poisson_probabilities_match_cdf() {
+        let rate = 1_400_000_000;
+        let sum: u64 = poisson_probabilities(rate).iter().sum();
+        assert!(sum.abs_diff(poisson_cdf(rate, CORRECT_SCORE_MAX_GOALS)) <= 4);
+    }
+
+    #[test]
+    fn test_btts_odds_in_range() {
+        let (yes, no) = calculate_btts_odds(&locked(1_500_000_000, 1_500_000_000, 1_700_000_000), BTTS_MARGIN_BPS);
+        assert!(yes >= MIN_COMPRESSED_ODDS && yes <= MAX_COMPRESSED_ODDS);
+        assert!(no >= MIN_COMPRESSED_ODDS && no <= MAX_COMPRESSED_ODDS);
+    }
+
+    #[test]
+    fn test_goal_difference_distribution_sums_to_one() {
+        let total: u64 = goal_difference_distribution(1_500_000_000, 1_100_000_000).iter().sum();
+        assert!(ODDS_SCALE - total < 1_000_000, "missing mass {}", ODDS_SCALE - total);
+    }
+
+    #[test]
+    fn test_asian_handicap_odds_lengthen_with_handicap() {
+        let odds = calculate_asian_handicap_odds(&locked(1_300_000_000, 2_100_000_000, 1_700_000_000), ASIAN_HANDICAP_MARGIN_BPS);
+
+        // Home side: giving more goals (more negative line) pays more
+        let home: Vec<u64> = (0..ASIAN_HANDICAP_LINES.len()).map(|i| odds[i * 2]).collect();
+        for pair in home.windows(2) {
+            assert!(pair[0] >= pair[1], "home odds should shorten as the line rises: {:?}", home);
+        }
+
+        // Level line: favourite shorter than underdog
+        let level = ASIAN_HANDICAP_LINES.iter().position(|&l| l == 0).unwrap();
+        assert!(odds[level * 2] < odds[level * 2 + 1]);
+
+        for &o in odds.iter() {
+            assert!(o >= MIN_ASIAN_HANDICAP_ODDS && o <= MAX_ASIAN_HANDICAP_ODDS);
+        }
+    }
+
+    #[test]
+    fn test_simulated_score_is_deterministic() {
+        let odds = locked(1_300_000_000, 2_000_000_000, 1_700_000_000);
+        let randomness = [7u8; 32];
+        assert_eq!(simulate_score(&randomness, &odds), simulate_score(&randomness, &odds));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::constants::*;
+
+/// Calculate the reward weight of a season prediction
+///
+/// Linear decay from PREDICTION_WEIGHT_SCALE (made before round 1) down towards
+/// MIN_PREDICTION_WEIGHT for the last round before the cut-off.
+/// Returns None once the cut-off has been reached.
+pub fn calculate_prediction_weight(rounds_elapsed: u64, cutoff_rounds: u64) -> Option<u64> {
+    if rounds_elapsed >= cutoff_rounds {
+        return None;
+    }
+
+    let remaining = cutoff_rounds - rounds_elapsed;
+    let decaying_range = PREDICTION_WEIGHT_SCALE - MIN_PREDICTION_WEIGHT;
+
+    let decaying = (decaying_range as u128)
+        .checked_mul(remaining as u128)?
+        .checked_div(cutoff_rounds as u128)? as u64;
+
+    Some(MIN_PREDICTION_WEIGHT + decaying)
+}
+
+/// Calculate a correct predictor's share of the season reward pool
+///
+/// share = pool × weight / total weight backing the winning team
+pub fn calculate_weighted_share(pool: u64, weight: u64, total_weight: u64) -> u64 {
+    if total_weight == 0 {
+        return 0;
+    }
+
+    (pool as u128)
+        .checked_mul(weight as u128)
+        .unwrap_or(0)
+        .checked_div(total_weight as u128)
+        .unwrap_or(0) as u64
+}
+
+/// Split the season reward snapshot between correct predictors and consolation
+///
+/// Losing predictors who burn their NFT share SEASON_CONSOLATION_SHARE_BPS of the pool.
+/// If nobody backed a losing team, winners keep the whole pool.
+/// Returns (winners_pool, consolation_pool)
+pub fn split_season_pool(snapshot: u64, winning_weight: u64, total_weight: u64) -> (u64, u64) {
+    let losing_weight = total_weight.saturating_sub(winning_weight);
+    if losing_weight == 0 {
+        return (snapshot, 0);
+    }
+
+    let consolation = (snapshot as u128)
+        .checked_mul(SEASON_CONSOLATION_SHARE_BPS as u128)
+        .unwrap_or(0)
+        .checked_div(BPS_DENOMINATOR as u128)
+        .unwrap_or(0) as u64;
+
+    (snapshot - consolation, consolation)
+}
+
+/// Part of a season's reward snapshot its predictors can claim
+///
+/// The winners' pool is only claimable if someone backed the winner, the
+/// consolation pool only if someone backed a losing team
+pub fn claimable_season_rewards(snapshot: u64, winning_weight: u64, total_weight: u64) -> u64 {
+    let (winners_pool, consolation_pool) = split_season_pool(snapshot, winning_weight, total_weight);
+    let winners_claimable = if winning_weight > 0 { winners_pool } else { 0 };
+
+    winners_claimable.saturating_add(consolation_pool)
+}
+
+/// Calculate a round's season pool share, moved into the season vault at finalization
+///
+/// share = (user deposits + protocol fees, i.e. user bets before fee) × share bps,
+/// capped at the round's operating profit (user deposits - payouts) so a losing round
+/// never funds the season from LP capital, and at the betting pool's unreserved
+/// balance (`available`). Returns None on overflow
+pub fn calculate_season_share(
+    user_deposits: u64,
+    protocol_fee_collected: u64,
+    total_paid: u64,
+    season_pool_share_bps: u16,
+    available: u64,
+) -> Option<u64> {
+    let total_user_bets_before_fee = user_deposits.saturating_add(protocol_fee_collected);
+
+    let share = (total_user_bets_before_fee as u128)
+        .checked_mul(season_pool_share_bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)? as u64;
+
+    let operating_profit = user_deposits.saturating_sub(total_paid);
+
+    Some(share.min(operating_profit).min(available))
+}
+
+/// Build the on-chain name of a season prediction NFT
+///
+/// Season and team are the key traits, so they are part of the name itself
+pub fn season_nft_name(season_id: u64, team: u8) -> String {
+    format!("S{} Team {}", season_id, team)
+}
+
+/// Build the metadata URI of a season prediction NFT
+///
+/// The off-chain JSON at this path carries the season, team and
+/// prediction timestamp as attributes
+pub fn season_nft_uri(base_uri: &str, season_id: u64, team: u8, predicted_at: i64) -> String {
+    format!(
+        "{}/{}/{}/{}.json",
+        base_uri.trim_end_matches('/'),
+        season_id,
+        team,
+        predicted_at
+    )
+}
+
+/// Build the name of a winning prediction NFT after redemption
+///
+/// Truncated to the Metaplex name limit (only reached by very large season IDs)
+pub fn season_nft_champion_name(season_id: u64, team: u8) -> String {
+    let mut name = format!("S{} Team {} Champion", season_id, team);
+    name.truncate(MAX_NFT_NAME_LEN);
+    name
+}
+
+/// Build the metadata URI of a winning prediction NFT after redemption
+///
+/// Served next to the original JSON with the "champion predictor" attribute added
+pub fn season_nft_champion_uri(base_uri: &str, season_id: u64, team: u8, predicted_at: i64) -> String {
+    format!(
+        "{}/{}/{}/{}-champion.json",
+        base_uri.trim_end_matches('/'),
+        season_id,
+        team,
+        predicted_at
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_before_round_one_is_full() {
+        assert_eq!(calculate_prediction_weight(0, 10), Some(PREDICTION_WEIGHT_SCALE));
+    }
+
+    #[test]
+    fn test_weight_decays_with_rounds() {
+        let mut previous = PREDICTION_WEIGHT_SCALE + 1;
+        for elapsed in 0..10 {
+            let weight = calculate_prediction_weight(elapsed, 10).unwrap();
+            assert!(weight < previous, "weight must strictly decay: {} !< {}", weight, previous);
+            assert!(weight >= MIN_PREDICTION_WEIGHT);
+            previous = weight;
+        }
+    }
+
+    #[test]
+    fn test_no_weight_after_cutoff() {
+        assert_eq!(calculate_prediction_weight(10, 10), None);
+        assert_eq!(calculate_prediction_weight(25, 10), None);
+        assert_eq!(calculate_prediction_weight(0, 0), None);
+    }
+
+    #[test]
+    fn test_weighted_shares_sum_to_pool() {
+        // Early (full weight) and late (min-ish weight) predictor split the pool
+        let pool = 1_000_000_000_000u64;
+        let early = calculate_prediction_weight(0, 10).unwrap();
+        let late = calculate_prediction_weight(9, 10).unwrap();
+        let total = early + late;
+
+        let early_share = calculate_weighted_share(pool, early, total);
+        let late_share = calculate_weighted_share(pool, late, total);
+
+        assert!(early_share > late_share);
+        assert!(early_share + late_share <= pool);
+        assert!(pool - (early_share + late_share) <= 1, "rounding dust only");
+    }
+
+    #[test]
+    fn test_weighted_share_zero_total() {
+        assert_eq!(calculate_weighted_share(1_000, 1, 0), 0);
+    }
+
+    #[test]
+    fn test_season_nft_name_fits_metaplex_limit() {
+        let name = season_nft_name(u64::MAX, 9);
+        assert!(name.len() <= MAX_NFT_NAME_LEN, "name too long: {} ({})", name, name.len());
+        assert_eq!(season_nft_name(3, 7), "S3 Team 7");
+    }
+
+    #[test]
+    fn test_season_nft_uri_encodes_traits() {
+        let uri = season_nft_uri("https://nft.example.com/season/", 2, 5, 1_700_000_000);
+        assert_eq!(uri, "https://nft.example.com/season/2/5/1700000000.json");
+
+        let longest_base = "x".repeat(MAX_NFT_BASE_URI_LEN);
+        let uri = season_nft_uri(&longest_base, u64::MAX, 9, i64::MIN);
+        assert!(uri.len() <= MAX_NFT_URI_LEN, "uri too long: {}", uri.len());
+    }
+
+    #[test]
+    fn test_season_share_of_user_bets_before_fee() {
+        // 2% of 9_500 deposits + 500 fees
+        assert_eq!(calculate_season_share(9_500, 500, 0, 200, u64::MAX), Some(200));
+        assert_eq!(calculate_season_share(0, 0, 0, 200, u64::MAX), Some(0));
+    }
+
+    #[test]
+    fn test_season_share_capped_at_available_balance() {
+        assert_eq!(calculate_season_share(9_500, 500, 0, 200, 150), Some(150));
+        assert_eq!(calculate_season_share(9_500, 500, 0, 200, 0), Some(0));
+        assert_eq!(calculate_season_share(u64::MAX, u64::MAX, 0, 10_000, 1_000), Some(1_000));
+    }
+
+    #[test]
+    fn test_season_share_capped_at_operating_profit() {
+        // Small profit: the share can't exceed it
+        assert_eq!(calculate_season_share(9_500, 500, 9_400, 200, u64::MAX), Some(100));
+
+        // Losing round: paid out more than users deposited, nothing comes out of LP seed
+        assert_eq!(calculate_season_share(9_500, 500, 12_000, 200, u64::MAX), Some(0));
+        assert_eq!(calculate_season_share(9_500, 500, 9_500, 200, u64::MAX), Some(0));
+    }
+
+    #[test]
+    fn test_split_season_pool_with_losers() {
+        let (winners, consolation) = split_season_pool(1_000_000, 3_000, 10_000);
+        assert_eq!(consolation, 1_000_000 * SEASON_CONSOLATION_SHARE_BPS / BPS_DENOMINATOR);
+        assert_eq!(winners + consolation, 1_000_000);
+    }
+
+    #[test]
+    fn test_split_season_pool_everyone_won() {
+        assert_eq!(split_season_pool(1_000_000, 5_000, 5_000), (1_000_000, 0));
+    }
+
+    #[test]
+    fn test_claimable_season_rewards() {
+        // Winners and losers: the whole snapshot is owed
+        assert_eq!(claimable_season_rewards(1_000_000, 3_000, 10_000), 1_000_000);
+
+        // Nobody backed the winner: only the consolation pool is owed
+        let (_, consolation) = split_season_pool(1_000_000, 0, 10_000);
+        assert_eq!(claimable_season_rewards(1_000_000, 0, 10_000), consolation);
+
+        // No predictions at all: nothing is owed
+        assert_eq!(claimable_season_rewards(1_000_000, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_season_nft_champion_metadata() {
+        let name = season_nft_champion_name(9_999, 9);
+        assert_eq!(name, "S9999 Team 9 Champion");
+
+        let name = season_nft_champion_name(u64::MAX, 9);
+        assert!(name.len() <= MAX_NFT_NAME_LEN, "name too long: {} ({})", name, name.len());
+        assert!(name.starts_with("S18446744073709551615 Team 9"));
+
+        let longest_base = "x".repeat(MAX_NFT_BASE_URI_LEN);
+        let uri = season_nft_champion_uri(&longest_base, u64::MAX, 9, i64::MIN);
+        assert!(uri.len() <= MAX_NFT_URI_LEN, "uri too long: {}", uri.len());
+    }
+}
@@ -1,14 +1,52 @@
 use crate::constants::*;
+use crate::state::LockedOdds;
+use crate::utils::odds::implied_probabilities;
 use anchor_lang::prelude::*;
 
+/// Convert a reference amount (written for a 9-decimal mint) into units of a mint with `decimals`
+pub fn scale_token_amount(amount: u64, decimals: u8) -> u64 {
+    if decimals >= REFERENCE_TOKEN_DECIMALS {
+        let factor = 10u64.saturating_pow((decimals - REFERENCE_TOKEN_DECIMALS) as u32);
+        amount.saturating_mul(factor)
+    } else {
+        amount / 10u64.pow((REFERENCE_TOKEN_DECIMALS - decimals) as u32)
+    }
+}
+
+/// Size a round's total seed from the LP's available liquidity
+///
+/// Takes `seed_fraction_bps` of the available liquidity, clamped between the
+/// per-round floor and ceiling (both scaled to the mint's decimals)
+pub fn calculate_round_seed(available_liquidity: u64, seed_fraction_bps: u16, decimals: u8) -> u64 {
+    let fraction = (available_liquidity as u128 * seed_fraction_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+    fraction.clamp(
+        scale_token_amount(MIN_SEED_PER_ROUND, decimals),
+        scale_token_amount(MAX_SEED_PER_ROUND, decimals),
+    )
+}
+
+/// Split a match's seed across home/away/draw in proportion to the implied probability of its odds
+///
+/// Used for oracle-priced rounds, so each outcome's seeded pool matches the price it was given
+pub fn calculate_seeds_from_odds(odds: &LockedOdds, total_seed: u64) -> (u64, u64, u64) {
+    let (home_probability, away_probability, _) = implied_probabilities(odds);
+    let share = |probability: u64| (total_seed as u128 * probability as u128 / ODDS_SCALE as u128) as u64;
+
+    let home_seed = share(home_probability);
+    let away_seed = share(away_probability);
+    (home_seed, away_seed, total_seed - home_seed - away_seed)
+}
+
 /// Calculate differentiated seed amounts for a match using pseudo-random allocation
 ///
 /// This creates varied odds (1.2x - 1.8x range) based on deterministic randomness
-/// from team IDs and round ID
+/// from team IDs and round ID, splitting `total_seed` across the three outcomes
 pub fn calculate_pseudo_random_seeds(
     home_team_id: u64,
     away_team_id: u64,
     round_id: u64,
+    total_seed: u64,
 ) -> (u64, u64, u64) {
     // Generate deterministic pseudo-random seed
     let hash_input = format!("{}-{}-{}", home_team_id, away_team_id, round_id);
@@ -19,8 +57,6 @@ pub fn calculate_pseudo_random_seeds(
     let away_strength = (hash.to_bytes()[1] as u64) % 100;
     let draw_factor = (hash.to_bytes()[2] as u64) % 100;
 
-    let total_seed = SEED_PER_MATCH;
-
     // Calculate strength difference
     let diff = if home_strength > away_strength {
         home_strength - away_strength
@@ -53,24 +89,25 @@ pub fn calculate_pseudo_random_seeds(
         (34, 34, 32)
     };
 
-    // Allocate pools
+    // Allocate pools (u128: seeds for high-decimal mints overflow u64 × percentage)
+    let share = |percent: u64| (total_seed as u128 * percent as u128 / 100) as u64;
     let (mut home_seed, mut away_seed, mut draw_seed) = if home_strength > away_strength {
         (
-            (total_seed * favorite_alloc) / 100,
-            (total_seed * underdog_alloc) / 100,
-            (total_seed * draw_alloc) / 100,
+            share(favorite_alloc),
+            share(underdog_alloc),
+            share(draw_alloc),
         )
     } else {
         (
-            (total_seed * underdog_alloc) / 100,
-            (total_seed * favorite_alloc) / 100,
-            (total_seed * draw_alloc) / 100,
+            share(underdog_alloc),
+            share(favorite_alloc),
+            share(draw_alloc),
         )
     };
 
     // Draw-heavy matchups (20% of matches get boosted draws)
     if draw_factor > 80 {
-        let draw_boost = (total_seed * 16) / 100; // Boost draw by 16%
+        let draw_boost = share(16); // Boost draw by 16%
         draw_seed = draw_seed.saturating_add(draw_boost);
         home_seed = home_seed.saturating_sub(draw_boost / 2);
         away_seed = away_seed.saturating_sub(draw_boost / 2);
@@ -89,13 +126,12 @@ pub fn calculate_stats_based_seeds(
     _away_team_id: u64,
     _home_points: u64,
     _away_points: u64,
+    total_seed: u64,
 ) -> (u64, u64, u64) {
     // Placeholder implementation - in production, this would use actual team stats
     // from a game engine or oracle
 
     // For now, return balanced seeding
-    let total_seed = SEED_PER_MATCH;
-
     // Balanced: 35/35/30 split
     let home_seed = (total_seed * 35) / 100;
     let away_seed = (total_seed * 35) / 100;
@@ -114,15 +150,16 @@ pub fn calculate_match_seeds(
     home_team_id: u64,
     away_team_id: u64,
     season_round: u64,
+    total_seed: u64,
 ) -> (u64, u64, u64) {
     // Use pseudo-random for first 3 rounds (no meaningful stats yet)
     if season_round <= 3 {
-        return calculate_pseudo_random_seeds(home_team_id, away_team_id, round_id);
+        return calculate_pseudo_random_seeds(home_team_id, away_team_id, round_id, total_seed);
     }
 
     // For rounds 4+, would use stats-based seeding
     // Placeholder: use pseudo-random for now
-    calculate_pseudo_random_seeds(home_team_id, away_team_id, round_id)
+    calculate_pseudo_random_seeds(home_team_id, away_team_id, round_id, total_seed)
 }
 
 #[cfg(test)]
@@ -145,7 +182,7 @@ mod tests {
 
     #[test]
     fn test_seeds_sum_to_total() {
-        let (home, away, draw) = calculate_pseudo_random_seeds(1, 2, 1);
+        let (home, away, draw) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
         // Draw-heavy boost can add up to 16% extra, so total may exceed SEED_PER_MATCH slightly
         let total = home + away + draw;
         // Should be close to SEED_PER_MATCH (within 20% for draw-heavy boosts)
@@ -155,7 +192,7 @@ mod tests {
 
     #[test]
     fn test_seeds_all_nonzero() {
-        let (home, away, draw) = calculate_pseudo_random_seeds(1, 2, 1);
+        let (home, away, draw) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
         assert!(home > 0, "home_seed must be nonzero");
         assert!(away > 0, "away_seed must be nonzero");
         assert!(draw > 0, "draw_seed must be nonzero");
@@ -163,8 +200,8 @@ mod tests {
 
     #[test]
     fn test_deterministic_seeding() {
-        let (h1, a1, d1) = calculate_pseudo_random_seeds(1, 2, 1);
-        let (h2, a2, d2) = calculate_pseudo_random_seeds(1, 2, 1);
+        let (h1, a1, d1) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
+        let (h2, a2, d2) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
         assert_eq!(h1, h2);
         assert_eq!(a1, a2);
         assert_eq!(d1, d2);
@@ -172,8 +209,8 @@ mod tests {
 
     #[test]
     fn test_different_teams_different_seeds() {
-        let (h1, a1, _) = calculate_pseudo_random_seeds(1, 2, 1);
-        let (h2, a2, _) = calculate_pseudo_random_seeds(3, 7, 1);
+        let (h1, a1, _) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
+        let (h2, a2, _) = calculate_pseudo_random_seeds(3, 7, 1, SEED_PER_MATCH);
         // Different team IDs should (almost certainly) produce different allocations
         assert!(h1 != h2 || a1 != a2, "Different teams should get different seeds");
     }
@@ -231,7 +268,7 @@ mod tests {
         let pairs = [(1u64, 2u64), (3, 5), (7, 10), (2, 9), (6, 8), (4, 1), (10, 3)];
         for (home_id, away_id) in pairs {
             for round_id in [1u64, 5, 10, 42] {
-                let (hs, aws, ds) = calculate_pseudo_random_seeds(home_id, away_id, round_id);
+                let (hs, aws, ds) = calculate_pseudo_random_seeds(home_id, away_id, round_id, SEED_PER_MATCH);
                 if hs > 0 && aws > 0 && ds > 0 {
                     let label = format!("team{}_vs_team{}_round{}", home_id, away_id, round_id);
                     assert_odds_in_range(hs, aws, ds, &label);
@@ -239,4 +276,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_round_seed_scales_with_lp_and_mint_decimals() {
+        // Reference amounts convert between mints
+        assert_eq!(scale_token_amount(SEED_PER_MATCH, 9), SEED_PER_MATCH);
+        assert_eq!(scale_token_amount(SEED_PER_MATCH, 6), 3_000_000_000); // 3000 tokens, 6 decimals
+        assert_eq!(scale_token_amount(SEED_PER_MATCH, 0), 3_000);
+        assert_eq!(scale_token_amount(MAX_PAYOUT_PER_BET, 18), u64::MAX, "saturates rather than overflows");
+
+        // 10% of a 100k-token LP seeds 10k tokens
+        let lp = 100_000_000_000_000;
+        assert_eq!(calculate_round_seed(lp, 1_000, 9), 10_000_000_000_000);
+
+        // A small LP is seeded at the floor instead of failing outright; a huge one at the ceiling
+        assert_eq!(calculate_round_seed(5_000_000_000_000, 1_000, 9), MIN_SEED_PER_ROUND);
+        assert_eq!(calculate_round_seed(u64::MAX, MAX_SEED_FRACTION_BPS, 9), MAX_SEED_PER_ROUND);
+
+        // The same LP in a 6-decimal mint seeds the same number of tokens
+        assert_eq!(calculate_round_seed(lp / 1_000, 1_000, 6), 10_000_000_000);
+        assert_eq!(calculate_round_seed(0, 1_000, 6), scale_token_amount(MIN_SEED_PER_ROUND, 6));
+
+        // Per-match seeds keep their split at any size
+        let (h, a, d) = calculate_pseudo_random_seeds(1, 2, 1, 3_000_000_000);
+        let (h9, a9, d9) = calculate_pseudo_random_seeds(1, 2, 1, SEED_PER_MATCH);
+        assert_eq!((h * 1_000, a * 1_000, d * 1_000), (h9, a9, d9));
+    }
+
+    #[test]
+    fn test_seeds_from_odds_match_submitted_prices() {
+        let odds = LockedOdds {
+            home_odds: 1_800_000_000,
+            away_odds: 4_500_000_000,
+            draw_odds: 3_600_000_000,
+            locked: true,
+        };
+
+        let (home, away, draw) = calculate_seeds_from_odds(&odds, SEED_PER_MATCH);
+        assert_eq!(home + away + draw, SEED_PER_MATCH);
+
+        // Pools are in the ratio of the book's implied probabilities (1/1.8 : 1/4.5 : 1/3.6 = 5 : 2 : 2.5)
+        assert!(home > draw && draw > away);
+        assert!((home as i128 * 2 - away as i128 * 5).abs() < 100_000);
+        assert!((draw as i128 * 2 - away as i128 * 5 / 2).abs() < 100_000);
+    }
 }
@@ -4,6 +4,9 @@
 /// in match outcome generation.
 
 use anchor_lang::prelude::*;
+use crate::state::{LockedOdds, MatchScore};
+use crate::utils::simulate_score;
+use crate::constants::VRF_BYTES_PER_MATCH;
 
 /// VRF Request account to track randomness requests
 #[account]
@@ -17,91 +20,114 @@ pub struct VrfRequest {
     /// Request timestamp
     pub request_time: i64,
 
+    /// Matches this request draws scorelines for (bit i = match i)
+    pub requested_matches: u32,
+
     /// Whether the request has been fulfilled
     pub fulfilled: bool,
 
     /// Fulfillment timestamp
     pub fulfillment_time: i64,
 
-    /// Randomness result (320 bytes = 32 bytes per match × 10 matches)
-    pub randomness: [u8; 320],
+    /// Randomness result (32 bytes per match in the round)
+    pub randomness: Vec<u8>,
 
     /// Match results derived from randomness
-    pub match_results: [u8; 10],
+    pub match_results: Vec<u8>,
+
+    /// Scorelines derived from randomness
+    pub match_scores: Vec<MatchScore>,
 
     /// Bump seed for PDA
     pub bump: u8,
 }
 
 impl VrfRequest {
-    pub const LEN: usize = 8 + // discriminator
+    /// Account size for a round of `num_matches` matches
+    pub fn space(num_matches: usize) -> usize {
+        8 + // discriminator
         8 +  // round_id
         32 + // vrf_account
         8 +  // request_time
+        4 +  // requested_matches
         1 +  // fulfilled
         8 +  // fulfillment_time
-        320 + // randomness (32 bytes × 10 matches)
-        10 + // match_results
-        1;   // bump
+        (4 + num_matches * VRF_BYTES_PER_MATCH) + // randomness
+        (4 + num_matches) +     // match_results
+        (4 + num_matches * 2) + // match_scores (2 bytes each)
+        1    // bump
+    }
 }
 
-/// Generate match outcome from randomness bytes
+/// Simulate one scoreline per match from VRF randomness
 ///
-/// Maps random bytes to match outcomes (1, 2, or 3)
-/// Each outcome has equal 33.33% probability
-pub fn outcome_from_randomness(randomness: &[u8]) -> u8 {
-    // Take first 8 bytes and convert to u64
-    let value = u64::from_le_bytes(randomness[0..8].try_into().unwrap());
-
-    // Map to outcome (1, 2, or 3) with equal probability
-    ((value % 3) + 1) as u8
+/// Each match uses its own 32-byte slice; goal rates come from the locked odds
+pub fn extract_match_scores(randomness: &[u8], locked_odds: &[LockedOdds]) -> Vec<MatchScore> {
+    randomness
+        .chunks_exact(VRF_BYTES_PER_MATCH)
+        .zip(locked_odds.iter())
+        .map(|(match_randomness, odds)| simulate_score(match_randomness, odds))
+        .collect()
 }
 
-/// Extract all 10 match results from VRF randomness
-pub fn extract_match_results(randomness: &[u8; 320]) -> [u8; 10] {
-    let mut results = [0u8; 10];
-
-    for i in 0..10 {
-        let offset = i * 32;
-        let match_randomness = &randomness[offset..offset + 32];
-        results[i] = outcome_from_randomness(match_randomness);
-    }
-
-    results
+/// Derive 1X2 match results (1, 2, or 3) from scorelines
+pub fn extract_match_results(scores: &[MatchScore]) -> Vec<u8> {
+    scores.iter().map(|score| score.outcome() as u8).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constants::MAX_GOALS_PER_TEAM;
+
+    fn default_odds(num_matches: usize) -> Vec<LockedOdds> {
+        vec![LockedOdds {
+            home_odds: 1_400_000_000,
+            away_odds: 2_000_000_000,
+            draw_odds: 1_700_000_000,
+            locked: true,
+        }; num_matches]
+    }
 
     #[test]
-    fn test_outcome_from_randomness() {
-        // Test with known values
-        let randomness1 = [0u8; 32];
-        let outcome1 = outcome_from_randomness(&randomness1);
-        assert!(outcome1 >= 1 && outcome1 <= 3);
-
-        let randomness2 = [255u8; 32];
-        let outcome2 = outcome_from_randomness(&randomness2);
-        assert!(outcome2 >= 1 && outcome2 <= 3);
-
-        // Test that different random values can produce different outcomes
-        let mut randomness3 = [0u8; 32];
-        randomness3[0] = 1;
-        let outcome3 = outcome_from_randomness(&randomness3);
-        assert!(outcome3 >= 1 && outcome3 <= 3);
+    fn test_extract_match_scores() {
+        let randomness: Vec<u8> = (0..10 * VRF_BYTES_PER_MATCH).map(|i| (i * 37 % 256) as u8).collect();
+        let scores = extract_match_scores(&randomness, &default_odds(10));
+        assert_eq!(scores.len(), 10);
+
+        for score in &scores {
+            assert!(score.home_goals <= MAX_GOALS_PER_TEAM);
+            assert!(score.away_goals <= MAX_GOALS_PER_TEAM);
+        }
+
+        // Zero randomness is the bottom of the CDF: 0-0
+        let scores = extract_match_scores(&[0u8; 320], &default_odds(10));
+        assert_eq!(scores[0], MatchScore { home_goals: 0, away_goals: 0 });
     }
 
     #[test]
     fn test_extract_match_results() {
         let randomness = [0u8; 320];
-        let results = extract_match_results(&randomness);
+        let scores = extract_match_scores(&randomness, &default_odds(10));
+        let results = extract_match_results(&scores);
 
-        // All results should be valid outcomes
-        for result in &results {
+        // All results should be valid outcomes consistent with the scorelines
+        for (result, score) in results.iter().zip(scores.iter()) {
             assert!(*result >= 1 && *result <= 3);
+            assert_eq!(*result, score.outcome() as u8);
         }
 
         assert_eq!(results.len(), 10);
     }
+
+    #[test]
+    fn test_extract_match_scores_follows_round_size() {
+        // One scoreline per 32-byte slice, for a 5-match and a 24-match round
+        for num_matches in [5usize, 24] {
+            let randomness: Vec<u8> = (0..num_matches * VRF_BYTES_PER_MATCH).map(|i| (i * 13 % 256) as u8).collect();
+            let scores = extract_match_scores(&randomness, &default_odds(num_matches));
+            assert_eq!(scores.len(), num_matches);
+            assert_eq!(extract_match_results(&scores).len(), num_matches);
+        }
+    }
 }
@@ -0,0 +1,336 @@
+//! Season prediction NFTs against the real Token Metadata program
+//!
+//! The sportsbook runs natively; Token Metadata is loaded from its mainnet build, dumped with
+//! `solana program dump -u m metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s tests/fixtures/mpl_token_metadata.so`
+
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::metadata::mpl_token_metadata::accounts::{MasterEdition, Metadata};
+use anchor_spl::metadata::mpl_token_metadata::types::CollectionDetails;
+use anchor_spl::metadata::mpl_token_metadata::ID as TOKEN_METADATA_ID;
+use solana_program_test::{find_file, processor, BanksClient, ProgramTest};
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use solana_sdk::{system_instruction, system_program, sysvar};
+use sportsbook::state::SeasonPrediction;
+
+const TOKEN_METADATA_FIXTURE: &str = "mpl_token_metadata.so";
+const WINNING_TEAM: u8 = 3;
+
+fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    // Anchor's entrypoint ties the account slice to the account lifetimes
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    sportsbook::entry(program_id, accounts, data)
+}
+
+fn metadata_pda(mint: &Pubkey) -> Pubkey {
+    Metadata::find_pda(mint).0
+}
+
+fn master_edition_pda(mint: &Pubkey) -> Pubkey {
+    MasterEdition::find_pda(mint).0
+}
+
+fn sportsbook_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &sportsbook::ID).0
+}
+
+async fn send(banks: &mut BanksClient, payer: &Keypair, signers: &[&Keypair], instructions: &[Instruction]) {
+    let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(1_400_000)];
+    all_instructions.extend_from_slice(instructions);
+
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let transaction =
+        Transaction::new_signed_with_payer(&all_instructions, Some(&payer.pubkey()), &all_signers, blockhash);
+    banks.process_transaction(transaction).await.unwrap();
+}
+
+async fn metadata(banks: &mut BanksClient, mint: &Pubkey) -> Metadata {
+    let account = banks.get_account(metadata_pda(mint)).await.unwrap().expect("metadata account");
+    assert_eq!(account.owner, TOKEN_METADATA_ID);
+    Metadata::from_bytes(&account.data).unwrap()
+}
+
+/// Create a token account for `owner` from a fresh keypair
+async fn create_token_account(banks: &mut BanksClient, payer: &Keypair, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let rent = banks.get_rent().await.unwrap();
+    send(
+        banks,
+        payer,
+        &[&account],
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), mint, owner).unwrap(),
+        ],
+    )
+    .await;
+    account.pubkey()
+}
+
+#[tokio::test]
+async fn test_prediction_nft_mint_verify_and_redeem() {
+    let Some(fixture) = find_file(TOKEN_METADATA_FIXTURE) else {
+        eprintln!("skipping: tests/fixtures/{} not found (see the module docs)", TOKEN_METADATA_FIXTURE);
+        return;
+    };
+    println!("Token Metadata program from {}", fixture.display());
+
+    let mut program_test = ProgramTest::new("sportsbook", sportsbook::ID, processor!(process_instruction));
+    program_test.prefer_bpf(true);
+    program_test.add_program("mpl_token_metadata", TOKEN_METADATA_ID, None);
+    let (mut banks, payer, _) = program_test.start().await;
+
+    // Betting token mint
+    let token_mint = Keypair::new();
+    let rent = banks.get_rent().await.unwrap();
+    send(
+        &mut banks,
+        &payer,
+        &[&token_mint],
+        &[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &token_mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::ID, &token_mint.pubkey(), &payer.pubkey(), None, 9)
+                .unwrap(),
+        ],
+    )
+    .await;
+
+    let betting_pool = sportsbook_pda(&[b"betting_pool"]);
+    let season_vault = sportsbook_pda(&[b"season_vault", betting_pool.as_ref()]);
+    let season_nft_config = sportsbook_pda(&[b"season_nft_config", betting_pool.as_ref()]);
+    let collection_mint = sportsbook_pda(&[b"season_collection", betting_pool.as_ref()]);
+
+    send(
+        &mut banks,
+        &payer,
+        &[],
+        &[
+            Instruction {
+                program_id: sportsbook::ID,
+                accounts: sportsbook::accounts::Initialize {
+                    betting_pool,
+                    liquidity_pool: sportsbook_pda(&[b"liquidity_pool", betting_pool.as_ref()]),
+                    authority: payer.pubkey(),
+                    token_mint: token_mint.pubkey(),
+                    protocol_treasury: Pubkey::new_unique(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: sportsbook::instruction::Initialize {
+                    protocol_fee_bps: 300,
+                    winner_share_bps: 2500,
+                    season_pool_share_bps: 200,
+                }
+                .data(),
+            },
+            Instruction {
+                program_id: sportsbook::ID,
+                accounts: sportsbook::accounts::InitializeSeasonVault {
+                    betting_pool,
+                    season_vault,
+                    token_mint: token_mint.pubkey(),
+                    authority: payer.pubkey(),
+                    token_program: spl_token::ID,
+                    system_program: system_program::ID,
+                    rent: sysvar::rent::ID,
+                }
+                .to_account_metas(None),
+                data: sportsbook::instruction::InitializeSeasonVault {}.data(),
+            },
+        ],
+    )
+    .await;
+
+    // Sized collection: metadata, master edition, collection NFT held by the betting pool
+    send(
+        &mut banks,
+        &payer,
+        &[],
+        &[Instruction {
+            program_id: sportsbook::ID,
+            accounts: sportsbook::accounts::InitializeSeasonCollection {
+                betting_pool,
+                season_nft_config,
+                collection_mint,
+                collection_token_account: get_associated_token_address(&betting_pool, &collection_mint),
+                collection_metadata: metadata_pda(&collection_mint),
+                collection_master_edition: master_edition_pda(&collection_mint),
+                authority: payer.pubkey(),
+                token_program: spl_token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_metadata_program: TOKEN_METADATA_ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: sportsbook::instruction::InitializeSeasonCollection {
+                name: "Season Predictions".to_string(),
+                symbol: "SZN".to_string(),
+                base_uri: "https://nft.example.com/season".to_string(),
+                collection_uri: "https://nft.example.com/season/collection.json".to_string(),
+            }
+            .data(),
+        }],
+    )
+    .await;
+
+    let collection = metadata(&mut banks, &collection_mint).await;
+    assert_eq!(collection.update_authority, betting_pool);
+    assert_eq!(collection.collection_details, Some(CollectionDetails::V1 { size: 0 }));
+
+    // Prediction: NFT minted to the predictor with metadata, a 1/1 master edition
+    // and a verified membership of the season collection
+    let user = Keypair::new();
+    send(
+        &mut banks,
+        &payer,
+        &[],
+        &[system_instruction::transfer(&payer.pubkey(), &user.pubkey(), 1_000_000_000)],
+    )
+    .await;
+
+    let season_id = 0u64.to_le_bytes();
+    let season_prediction = sportsbook_pda(&[
+        b"season_prediction",
+        betting_pool.as_ref(),
+        season_id.as_ref(),
+        user.pubkey().as_ref(),
+    ]);
+    let nft_mint = sportsbook_pda(&[
+        b"prediction_nft",
+        betting_pool.as_ref(),
+        season_id.as_ref(),
+        user.pubkey().as_ref(),
+    ]);
+    let user_nft_account = get_associated_token_address(&user.pubkey(), &nft_mint);
+
+    send(
+        &mut banks,
+        &payer,
+        &[&user],
+        &[Instruction {
+            program_id: sportsbook::ID,
+            accounts: sportsbook::accounts::MakeSeasonPrediction {
+                betting_pool,
+                season_prediction,
+                nft_mint,
+                user_nft_account,
+                season_nft_config,
+                nft_metadata: metadata_pda(&nft_mint),
+                nft_master_edition: master_edition_pda(&nft_mint),
+                collection_mint,
+                collection_metadata: metadata_pda(&collection_mint),
+                collection_master_edition: master_edition_pda(&collection_mint),
+                user: user.pubkey(),
+                token_program: spl_token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                token_metadata_program: TOKEN_METADATA_ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: sportsbook::instruction::MakeSeasonPrediction { predicted_team: WINNING_TEAM }.data(),
+        }],
+    )
+    .await;
+
+    let nft_account = banks.get_account(user_nft_account).await.unwrap().unwrap();
+    assert_eq!(spl_token::state::Account::unpack(&nft_account.data).unwrap().amount, 1);
+
+    let prediction = metadata(&mut banks, &nft_mint).await;
+    assert_eq!(prediction.update_authority, betting_pool);
+    assert_eq!(prediction.name.trim_end_matches('\0'), "S0 Team 3");
+    assert_eq!(prediction.symbol.trim_end_matches('\0'), "SZN");
+    assert!(prediction.uri.trim_end_matches('\0').starts_with("https://nft.example.com/season/0/3/"));
+    let prediction_collection = prediction.collection.expect("collection set");
+    assert_eq!(prediction_collection.key, collection_mint);
+    assert!(prediction_collection.verified, "collection membership must be verified");
+
+    let master_edition = banks.get_account(master_edition_pda(&nft_mint)).await.unwrap().expect("master edition");
+    assert_eq!(master_edition.owner, TOKEN_METADATA_ID);
+    assert_eq!(MasterEdition::from_bytes(&master_edition.data).unwrap().max_supply, Some(0));
+
+    let collection = metadata(&mut banks, &collection_mint).await;
+    assert_eq!(collection.collection_details, Some(CollectionDetails::V1 { size: 1 }));
+
+    // End the season on the predicted team, then redeem: the NFT is upgraded in place
+    let season_result = sportsbook_pda(&[b"season_result", betting_pool.as_ref(), season_id.as_ref()]);
+    send(
+        &mut banks,
+        &payer,
+        &[],
+        &[Instruction {
+            program_id: sportsbook::ID,
+            accounts: sportsbook::accounts::EndSeason {
+                betting_pool,
+                season_outright: sportsbook_pda(&[b"season_outright", betting_pool.as_ref(), season_id.as_ref()]),
+                season_result,
+                authority: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: sportsbook::instruction::EndSeason { winning_team: WINNING_TEAM }.data(),
+        }],
+    )
+    .await;
+
+    let holder_token_account = create_token_account(&mut banks, &payer, &token_mint.pubkey(), &user.pubkey()).await;
+    send(
+        &mut banks,
+        &payer,
+        &[&user],
+        &[Instruction {
+            program_id: sportsbook::ID,
+            accounts: sportsbook::accounts::RedeemSeasonNft {
+                betting_pool,
+                season_prediction,
+                season_result,
+                season_nft_config,
+                nft_mint,
+                nft_token_account: user_nft_account,
+                nft_metadata: metadata_pda(&nft_mint),
+                season_vault,
+                holder_token_account,
+                holder: user.pubkey(),
+                token_program: spl_token::ID,
+                token_metadata_program: TOKEN_METADATA_ID,
+            }
+            .to_account_metas(None),
+            data: sportsbook::instruction::RedeemSeasonNft {}.data(),
+        }],
+    )
+    .await;
+
+    let champion = metadata(&mut banks, &nft_mint).await;
+    assert_eq!(champion.name.trim_end_matches('\0'), "S0 Team 3 Champion");
+    assert!(champion.uri.trim_end_matches('\0').ends_with("-champion.json"));
+    let champion_collection = champion.collection.expect("collection kept");
+    assert_eq!(champion_collection.key, collection_mint);
+    assert!(champion_collection.verified, "upgrade must keep the verified collection");
+
+    let prediction_account = banks.get_account(season_prediction).await.unwrap().unwrap();
+    let prediction = SeasonPrediction::try_deserialize(&mut prediction_account.data.as_slice()).unwrap();
+    assert!(prediction.nft_redeemed);
+}
@@ -11,6 +11,10 @@ describe("Security Fixes Integration Tests", () => {
 
   const program = anchor.workspace.Sportsbook as Program;
 
+  // initialize_round arguments: fixed-odds pricing, 10 matches per round
+  const PRICING_FIXED_ODDS = 0;
+  const MATCHES_PER_ROUND = 10;
+
   let tokenMint: PublicKey;
   let authority: Keypair;
   let bettingPool: PublicKey;
@@ -80,8 +84,9 @@ describe("Security Fixes Integration Tests", () => {
       try {
         await program.methods
           .initialize(
-            500, // protocol_fee_bps (5%)
-            200  // season_pool_share_bps (2%)
+            500,  // protocol_fee_bps (5%)
+            2500, // winner_share_bps (25%)
+            200   // season_pool_share_bps (2%)
           )
           .accounts({
             bettingPool,
@@ -114,7 +119,7 @@ describe("Security Fixes Integration Tests", () => {
 
       try {
         await program.methods
-          .initializeRound(new anchor.BN(nextRoundId.toNumber() + 10))
+          .initializeRound(new anchor.BN(nextRoundId.toNumber() + 10), PRICING_FIXED_ODDS, MATCHES_PER_ROUND)
           .accounts({
             bettingPool,
             roundAccounting: wrongRoundPda,
@@ -146,7 +151,7 @@ describe("Security Fixes Integration Tests", () => {
 
       try {
         await program.methods
-          .initializeRound(nextRoundId)
+          .initializeRound(nextRoundId, PRICING_FIXED_ODDS, MATCHES_PER_ROUND)
           .accounts({
             bettingPool,
             roundAccounting: roundPda,
@@ -191,7 +196,7 @@ describe("Security Fixes Integration Tests", () => {
 
       try {
         await program.methods
-          .initialize(500, 200)
+          .initialize(500, 2500, 200)
           .accounts({
             bettingPool: fakeBettingPool,
             liquidityPool: fakeLiquidityPool,